@@ -18,6 +18,20 @@ impl fmt::Display for DatadogErrorResponse {
 }
 impl error::Error for DatadogErrorResponse {}
 
+impl DatadogErrorResponse {
+  // Parse an error body, keeping the raw body when it is not a Datadog error list
+  pub(crate) fn from_body(body: String) -> DatadogErrorResponse {
+    serde_json::from_str::<DatadogErrorResponse>(&body)
+      .ok()
+      .filter(|error| !error.errors.is_empty())
+      .unwrap_or(DatadogErrorResponse { errors: vec![body] })
+  }
+  // Whether the API reported the requested object as missing, e.g. `Monitor not found`
+  pub(crate) fn is_not_found(&self) -> bool {
+    self.errors.iter().any(|error| error.to_lowercase().contains("not found"))
+  }
+}
+
 // Wrapper for parsed Datadog API HTTP Response, whether success or error
 pub type DatadogResult<T> = result::Result<T, Box<dyn error::Error>>;
 
@@ -65,7 +79,7 @@ impl Client {
     Ok(res)
   }
 
-  pub async fn get<'de, T: Serialize, R: DeserializeOwned>(
+  pub async fn get<T: Serialize, R: DeserializeOwned>(
     &self,
     path_and_query: &str
   ) -> result::Result<R, Box<dyn error::Error>> {
@@ -80,11 +94,8 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
-          &body,
-        )?))
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
@@ -123,7 +134,7 @@ impl Client {
     Ok(res)
   }
 
-  pub async fn post<'de, T: Serialize, R: DeserializeOwned>(
+  pub async fn post<T: Serialize, R: DeserializeOwned>(
     &self,
     path_and_query: &str,
     json: &T,
@@ -139,16 +150,13 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
-          &body,
-        )?))
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
 
-  pub async fn post_str<'de, R: DeserializeOwned>(
+  pub async fn post_str<R: DeserializeOwned>(
     &self,
     path_and_query: &str,
     json_str: &str,
@@ -163,11 +171,8 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
-          &body,
-        )?))
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
@@ -206,7 +211,7 @@ impl Client {
     Ok(res)
   }
 
-  pub async fn put<'de, T: Serialize, R: DeserializeOwned>(
+  pub async fn put<T: Serialize, R: DeserializeOwned>(
     &self,
     path_and_query: &str,
     json: &T,
@@ -221,16 +226,13 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
-          &body,
-        )?))
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
 
-  pub async fn put_str<'de, R: DeserializeOwned>(
+  pub async fn put_str<R: DeserializeOwned>(
     &self,
     path_and_query: &str,
     json_str: &str,
@@ -245,11 +247,46 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
+      }
+    }
+  }
+
+  pub async fn patch_json<T: Serialize>(
+    &self,
+    path_and_query: &str,
+    json: &T,
+  ) -> result::Result<reqwest::Response, Box<dyn error::Error>> {
+    let url = format!("{}{}", self.host, path_and_query);
+    let res = self
+      .client
+      .patch(url)
+      .header("DD-API-KEY", self.api_key.to_string())
+      .header("DD-APPLICATION-KEY", self.application_key.to_string())
+      .json(&json)
+      .send()
+      .await?;
+    Ok(res)
+  }
+
+  pub async fn patch<T: Serialize, R: DeserializeOwned>(
+    &self,
+    path_and_query: &str,
+    json: &T,
+  ) -> result::Result<R, Box<dyn error::Error>> {
+    let resp = self.patch_json(path_and_query, json).await?;
+
+    match &resp.status().is_success() {
+      true => {
+        let body = resp.text().await?;
+        Ok(serde_json::from_str::<R>(
           &body,
-        )?))
+        )?)
+      }
+      _ => {
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
@@ -288,7 +325,7 @@ impl Client {
     Ok(res)
   }
 
-  pub async fn delete<'de, T: Serialize, R: DeserializeOwned>(
+  pub async fn delete<T: Serialize, R: DeserializeOwned>(
     &self,
     path_and_query: &str,
     json: &T,
@@ -303,16 +340,13 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
-          &body,
-        )?))
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
 
-  pub async fn delete_str<'de, R: DeserializeOwned>(
+  pub async fn delete_str<R: DeserializeOwned>(
     &self,
     path_and_query: &str,
     json_str: &str,
@@ -327,12 +361,19 @@ impl Client {
         )?)
       }
       _ => {
-        let body = &resp.text().await?;
-        println!("{:?}", &body);
-        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(
-          &body,
-        )?))
+        let body = resp.text().await?;
+        Err(Box::new(DatadogErrorResponse::from_body(body)))
       }
     }
   }
 }
+
+// Join a path and its query parameters, percent-encoding every value
+pub(crate) fn path_with_query(path: &str, queries: &[(&str, String)]) -> String {
+  let mut url = reqwest::Url::parse("http://localhost").expect("Invalid base URL");
+  url.query_pairs_mut().extend_pairs(queries);
+  match url.query() {
+    Some(query) if !queries.is_empty() => format!("{}?{}", path, query),
+    _ => path.to_string(),
+  }
+}
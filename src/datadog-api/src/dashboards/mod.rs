@@ -1,6 +1,8 @@
 mod widgets;
 
 pub use widgets::*;
+use async_gen::{self, AsyncIter};
+use futures_core::Stream;
 use serde::{Serialize, Deserialize};
 use crate::client::{*};
 
@@ -11,6 +13,7 @@ pub enum LayoutType {
   #[serde(rename = "free")]
   Free,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for LayoutType {
   fn default() -> LayoutType {
     LayoutType::Ordered
@@ -59,6 +62,16 @@ impl Widget {
     self.definition = Some(definition);
     self
   }
+  pub fn layout(mut self, layout: WidgetLayout) -> Widget {
+    self.layout = Some(layout);
+    self
+  }
+}
+
+impl From<WidgetDefinition> for Widget {
+  fn from(definition: WidgetDefinition) -> Widget {
+    Widget::default().definition(definition)
+  }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
@@ -108,7 +121,7 @@ impl UpdateDashboardRequest {
 impl UpdateDashboardRequest {
   pub async fn send(&self, client: &Client) -> DatadogResult<UpdateDashboardResponse> {
     let path_and_query = format!("/api/v1/dashboard/{}", self.dashboard_id);
-    client.put::<UpdateDashboardRequest, UpdateDashboardResponse>(&path_and_query, &self).await
+    client.put::<UpdateDashboardRequest, UpdateDashboardResponse>(&path_and_query, self).await
   }
 }
 
@@ -182,7 +195,7 @@ impl CreateDashboardRequest {
 
   pub async fn send(&self, client: &Client) -> DatadogResult<CreateDashboardResponse> {
     let path_and_query = "/api/v1/dashboard";
-    client.post::<CreateDashboardRequest, CreateDashboardResponse>(&path_and_query, &self).await
+    client.post::<CreateDashboardRequest, CreateDashboardResponse>(path_and_query, self).await
   }
 }
 
@@ -220,4 +233,295 @@ pub struct CreateDashboardResponse {
   pub url: String,
   /// List of widgets to display on the dashboard.
   pub widgets: Vec<Widget>,
-}
\ No newline at end of file
+}
+
+impl From<CreateDashboardResponse> for CreateDashboardRequest {
+  /// Start a new definition from a fetched dashboard, keeping every field that was set.
+  fn from(dashboard: CreateDashboardResponse) -> CreateDashboardRequest {
+    CreateDashboardRequest {
+      description: dashboard.description,
+      is_read_only: Some(dashboard.is_read_only),
+      layout_type: dashboard.layout_type,
+      notify_list: dashboard.notify_list,
+      reflow_type: dashboard.reflow_type,
+      restricted_roles: dashboard.restricted_roles,
+      template_variable_presets: dashboard.template_variable_presets,
+      template_variables: dashboard.template_variables,
+      title: dashboard.title,
+      widgets: dashboard.widgets,
+    }
+  }
+}
+
+/// [Get a dashboard](https://docs.datadoghq.com/api/latest/dashboards/#get-a-dashboard)
+///
+/// Get a dashboard using the specified ID, with its full definition.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GetDashboardRequest {
+  // Path
+  /// The ID of the dashboard.
+  #[serde(skip_serializing)]
+  pub dashboard_id: String,
+}
+impl GetDashboardRequest {
+  pub fn dashboard_id(mut self, dashboard_id: &str) -> GetDashboardRequest {
+    self.dashboard_id = dashboard_id.into();
+    self
+  }
+
+  pub async fn send(&self, client: &Client) -> DatadogResult<GetDashboardResponse> {
+    let path_and_query = format!("/api/v1/dashboard/{}", self.dashboard_id);
+    client.get::<GetDashboardRequest, GetDashboardResponse>(&path_and_query).await
+  }
+}
+
+/// A dashboard with its full definition.
+pub type GetDashboardResponse = CreateDashboardResponse;
+
+/// [Get all dashboards](https://docs.datadoghq.com/api/latest/dashboards/#get-all-dashboards)
+///
+/// Get a summary of the dashboards of the organization, without their widgets.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListDashboardsRequest {
+  // Query
+  /// When `true`, only shared custom created or cloned dashboards are returned.
+  #[serde(skip_serializing)]
+  pub filter_shared: Option<bool>,
+  /// When `true`, only deleted dashboards are returned.
+  #[serde(skip_serializing)]
+  pub filter_deleted: Option<bool>,
+  /// The maximum number of dashboards returned in the list. (Defaults to 100)
+  #[serde(skip_serializing)]
+  pub count: Option<u64>,
+  /// The specific offset to use as the beginning of the returned response.
+  #[serde(skip_serializing)]
+  pub start: Option<u64>,
+}
+impl ListDashboardsRequest {
+  pub fn filter_shared(mut self, filter_shared: bool) -> ListDashboardsRequest {
+    self.filter_shared = Some(filter_shared);
+    self
+  }
+  pub fn filter_deleted(mut self, filter_deleted: bool) -> ListDashboardsRequest {
+    self.filter_deleted = Some(filter_deleted);
+    self
+  }
+  pub fn count(mut self, count: u64) -> ListDashboardsRequest {
+    self.count = Some(count);
+    self
+  }
+  pub fn start(mut self, start: u64) -> ListDashboardsRequest {
+    self.start = Some(start);
+    self
+  }
+}
+
+impl ListDashboardsRequest {
+  pub async fn send(&self, client: &Client) -> DatadogResult<ListDashboardsResponse> {
+    let mut queries: Vec<String> = vec![];
+    if let Some(filter_shared) = &self.filter_shared {
+      queries.push(format!("filter[shared]={}", &filter_shared));
+    }
+    if let Some(filter_deleted) = &self.filter_deleted {
+      queries.push(format!("filter[deleted]={}", &filter_deleted));
+    }
+    if let Some(count) = &self.count {
+      queries.push(format!("count={}", &count));
+    }
+    if let Some(start) = &self.start {
+      queries.push(format!("start={}", &start));
+    }
+    let path = "/api/v1/dashboard";
+    let path_and_query = match queries.len() {
+      0 => path.to_string(),
+      _ => format!("{}?{}", &path, queries.join("&")),
+    };
+
+    client.get::<ListDashboardsRequest, ListDashboardsResponse>(&path_and_query).await
+  }
+
+  pub fn iter<'a>(&'a self, client: &'a Client) -> impl Stream<Item = DashboardSummary> + 'a {
+    AsyncIter::from(async_gen::gen! {
+      let count = self.count.unwrap_or(100);
+      let mut start = self.start.unwrap_or(0);
+      loop {
+        let request = ListDashboardsRequest {
+          filter_shared: self.filter_shared,
+          filter_deleted: self.filter_deleted,
+          count: Some(count),
+          start: Some(start),
+        };
+        let result = request.send(client).await.expect("Failed to call .send()");
+        let page_count = result.dashboards.len() as u64;
+        // Iterate over all results
+        for item in result.dashboards {
+          yield item;
+        }
+        // Stop iterating when we hit the last page
+        if page_count < count {
+          return;
+        }
+        start += page_count;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListDashboardsResponse {
+  /// List of dashboard definitions.
+  pub dashboards: Vec<DashboardSummary>,
+}
+
+/// Dashboard definition, without its widgets, as listed by [`ListDashboardsRequest`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashboardSummary {
+  /// Identifier of the dashboard author.
+  pub author_handle: String,
+  /// Creation date of the dashboard.
+  pub created_at: String,
+  /// Description of the dashboard.
+  pub description: Option<String>,
+  /// Dashboard identifier.
+  pub id: String,
+  /// Whether this dashboard is read-only. If True, only the author and admins can make changes to it.
+  pub is_read_only: bool,
+  /// Layout type of the dashboard. Allowed enum values: `ordered`, `free`
+  pub layout_type: LayoutType,
+  /// Modification date of the dashboard.
+  pub modified_at: String,
+  /// Title of the dashboard.
+  pub title: String,
+  /// URL of the dashboard.
+  pub url: String,
+}
+
+/// [Delete a dashboard](https://docs.datadoghq.com/api/latest/dashboards/#delete-a-dashboard)
+///
+/// Deleted dashboards can be restored with [`RestoreDashboardsRequest`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteDashboardRequest {
+  // Path
+  /// The ID of the dashboard.
+  #[serde(skip_serializing)]
+  pub dashboard_id: String,
+}
+impl DeleteDashboardRequest {
+  pub fn dashboard_id(mut self, dashboard_id: &str) -> DeleteDashboardRequest {
+    self.dashboard_id = dashboard_id.into();
+    self
+  }
+
+  pub async fn send(&self, client: &Client) -> DatadogResult<DeleteDashboardResponse> {
+    let path_and_query = format!("/api/v1/dashboard/{}", self.dashboard_id);
+    client.delete::<DeleteDashboardRequest, DeleteDashboardResponse>(&path_and_query, self).await
+  }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteDashboardResponse {
+  /// ID of the deleted dashboard.
+  pub deleted_dashboard_id: String,
+}
+
+/// A dashboard targeted by a bulk action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashboardBulkActionData {
+  /// Dashboard resource ID.
+  pub id: String,
+  /// Dashboard resource type. Allowed enum values: `dashboard`
+  #[serde(rename = "type")]
+  pub _type: String,
+}
+impl Default for DashboardBulkActionData {
+  fn default() -> DashboardBulkActionData {
+    DashboardBulkActionData {
+      id: String::new(),
+      _type: "dashboard".into(),
+    }
+  }
+}
+
+impl From<&str> for DashboardBulkActionData {
+  fn from(id: &str) -> DashboardBulkActionData {
+    DashboardBulkActionData {
+      id: id.into(),
+      ..Default::default()
+    }
+  }
+}
+
+/// [Delete dashboards](https://docs.datadoghq.com/api/latest/dashboards/#delete-dashboards)
+///
+/// Delete dashboards in bulk.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteDashboardsRequest {
+  /// The dashboards to delete.
+  pub data: Vec<DashboardBulkActionData>,
+}
+impl DeleteDashboardsRequest {
+  pub fn dashboard_id(mut self, dashboard_id: &str) -> DeleteDashboardsRequest {
+    self.data.push(dashboard_id.into());
+    self
+  }
+
+  pub async fn send(&self, client: &Client) -> DatadogResult<DeleteDashboardsResponse> {
+    let path_and_query = "/api/v1/dashboard";
+
+    let resp = client.delete_json(path_and_query, self).await?;
+    match resp.status().is_success() {
+      true => Ok(DeleteDashboardsResponse {}),
+      false => {
+        let body = resp.text().await?;
+        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(&body)?))
+      }
+    }
+  }
+}
+
+/// Response of a successful bulk deletion. (Always empty)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteDashboardsResponse {}
+
+/// [Restore deleted dashboards](https://docs.datadoghq.com/api/latest/dashboards/#restore-deleted-dashboards)
+///
+/// Restore dashboards in bulk.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RestoreDashboardsRequest {
+  /// The dashboards to restore.
+  pub data: Vec<DashboardBulkActionData>,
+}
+impl RestoreDashboardsRequest {
+  pub fn dashboard_id(mut self, dashboard_id: &str) -> RestoreDashboardsRequest {
+    self.data.push(dashboard_id.into());
+    self
+  }
+
+  pub async fn send(&self, client: &Client) -> DatadogResult<RestoreDashboardsResponse> {
+    let path_and_query = "/api/v1/dashboard";
+
+    let resp = client.patch_json(path_and_query, self).await?;
+    match resp.status().is_success() {
+      true => Ok(RestoreDashboardsResponse {}),
+      false => {
+        let body = resp.text().await?;
+        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(&body)?))
+      }
+    }
+  }
+}
+
+/// Response of a successful bulk restore. (Always empty)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RestoreDashboardsResponse {}
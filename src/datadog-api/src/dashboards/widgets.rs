@@ -1,59 +1,74 @@
 // https://docs.datadoghq.com/dashboards/widgets/
 use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
 
+use super::{LayoutType, Widget};
+use crate::monitors::GetMonitorResponse;
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum WidgetDefinition {
   #[serde(rename = "alert_graph")]
-  AlertGraph,
+  AlertGraph(AlertGraph),
   #[serde(rename = "alert_value")]
-  AlertValue,
+  AlertValue(AlertValue),
   #[serde(rename = "change")]
-  Change,
+  Change(Change),
   #[serde(rename = "check_status")]
-  CheckStatus,
+  CheckStatus(CheckStatus),
   #[serde(rename = "distribution")]
-  Distribution,
+  Distribution(Distribution),
   #[serde(rename = "event_stream")]
-  EventStream,
+  EventStream(EventStream),
   #[serde(rename = "event_timeline")]
-  EventTimeline,
+  EventTimeline(EventTimeline),
   #[serde(rename = "free_text")]
-  FreeText,
+  FreeText(FreeText),
   #[serde(rename = "geomap")]
-  Geomap,
+  Geomap(Geomap),
   #[serde(rename = "group")]
-  Group,
+  Group(Group),
   #[serde(rename = "heatmap")]
-  HeatMap,
+  HeatMap(HeatMap),
   #[serde(rename = "hostmap")]
-  Hostmap,
+  Hostmap(Hostmap),
   #[serde(rename = "iframe")]
-  Iframe,
+  Iframe(Iframe),
   #[serde(rename = "image")]
-  Image,
+  Image(Image),
   #[serde(rename = "log_stream")]
-  LogStream,
+  LogStream(LogStream),
   #[serde(rename = "manage_status")]
-  MonitorSummary,
+  MonitorSummary(MonitorSummary),
   #[serde(rename = "note")]
   NotesAndLinks(NotesAndLinks),
   #[serde(rename = "query_value")]
   QueryValue(QueryValue),
   #[serde(rename = "scatterplot")]
-  ScatterPlot,
+  ScatterPlot(ScatterPlot),
   #[serde(rename = "slo")]
-  ServiceLevelObjective,
+  ServiceLevelObjective(ServiceLevelObjective),
   #[serde(rename = "servicemap")]
-  ServiceMap,
+  ServiceMap(ServiceMap),
   #[serde(rename = "trace_service")]
-  ServiceSummary,
+  ServiceSummary(ServiceSummary),
   #[serde(rename = "query_table")]
-  Table,
+  Table(Table),
   #[serde(rename = "timeseries")]
-  TimeSeries,
+  TimeSeries(TimeSeries),
   #[serde(rename = "toplist")]
-  TopList,
+  TopList(TopList),
+  /// A widget type this crate doesn't know yet, e.g. `sunburst` or `funnel`, kept as is so it serializes back unchanged.
+  /// Known widget types whose definition doesn't parse end up here too, rather than failing the whole dashboard.
+  #[serde(untagged)]
+  Unknown {
+    #[serde(rename = "type")]
+    _type: String,
+    /// Every other field of the definition.
+    #[serde(flatten)]
+    definition: Value,
+  },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -65,6 +80,7 @@ pub enum TextAlign {
   #[serde(rename = "right")]
   Right,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for TextAlign {
   fn default() -> TextAlign {
     TextAlign::Left
@@ -80,6 +96,7 @@ pub enum VerticalAlign {
   #[serde(rename = "bottom")]
   Bottom,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for VerticalAlign {
   fn default() -> VerticalAlign {
     VerticalAlign::Top
@@ -107,6 +124,7 @@ pub enum RequestAggregator {
   #[serde(rename = "percentile")]
   Percentile,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for RequestAggregator {
   fn default() -> RequestAggregator {
     RequestAggregator::Average
@@ -140,6 +158,7 @@ pub enum MetricAggregator {
   #[serde(rename = "percentile")]
   Percentile,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for MetricAggregator {
   fn default() -> MetricAggregator {
     MetricAggregator::Average
@@ -165,6 +184,9 @@ pub struct NotesAndLinks {
   pub tick_pos: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub vertical_align: Option<VerticalAlign>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
 }
 impl Default for NotesAndLinks {
   fn default() -> NotesAndLinks {
@@ -178,45 +200,283 @@ impl Default for NotesAndLinks {
       tick_edge: None,
       tick_pos: None,
       vertical_align: None,
+      extra: Map::new(),
     }
   }
 }
 
 /// List of custom links.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CustomLink {
   /// The flag for toggling context menu link visibility.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub is_hidden: Option<bool>,
   /// The label for the custom link URL. Keep the label short and descriptive. Use metrics and tags as variables.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub label: Option<bool>,
+  pub label: Option<String>,
   /// The URL of the custom link. URL must include `http` or `https`. A relative URL must start with `/`.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub link: Option<bool>,
+  pub link: Option<String>,
   /// The label ID that refers to a context menu link. Can be `logs`, `hosts`, `traces`, `profiles`, `processes`, `containers`, or `rum`.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub override_label: Option<bool>,
+  pub override_label: Option<String>,
+}
+impl CustomLink {
+  pub fn label(mut self, label: &str) -> CustomLink {
+    self.label = Some(label.into());
+    self
+  }
+  pub fn link(mut self, link: &str) -> CustomLink {
+    self.link = Some(link.into());
+    self
+  }
 }
 
 /// Time setting for the widget.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Time {
   /// The available timeframes depend on the widget you are using. Allowed enum values: `1m`,`5m`,`10m`,`15m`,`30m`,`1h`,`4h`,`1d`,`2d`,`1w`,`1mo`,`3mo`,`6mo`,`1y`,`alert`
   #[serde(skip_serializing_if = "Option::is_none")]
   pub live_span: Option<String>, 
 }
+impl Time {
+  pub fn live_span(mut self, live_span: &str) -> Time {
+    self.live_span = Some(live_span.into());
+    self
+  }
+}
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Formula {
   /// Expression alias.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub alias: Option<String>,
-  // pub cell_display_mode: String,
-  // pub conditional_formats: String,
+  /// Define a display mode for the table cell. Allowed enum values: `number`,`bar`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cell_display_mode: Option<CellDisplayMode>,
+  /// List of conditional formats.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub conditional_formats: Option<Vec<ConditionalFormat>>,
   /// String expression built from queries, formulas, and functions.
   pub formula: String,
-  // pub limit:
+  /// Options for limiting results returned.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<FormulaLimit>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Formula {
+  pub fn alias(mut self, alias: &str) -> Formula {
+    self.alias = Some(alias.into());
+    self
+  }
+  pub fn cell_display_mode(mut self, cell_display_mode: CellDisplayMode) -> Formula {
+    self.cell_display_mode = Some(cell_display_mode);
+    self
+  }
+  pub fn conditional_format(mut self, conditional_format: ConditionalFormat) -> Formula {
+    self.conditional_formats.get_or_insert_with(Vec::new).push(conditional_format);
+    self
+  }
+  /// Only return the first `count` results in `order`.
+  pub fn limit(mut self, count: i64, order: SortOrder) -> Formula {
+    self.limit = Some(FormulaLimit {
+      count: Some(count),
+      order: Some(order),
+    });
+    self
+  }
+}
+impl From<&str> for Formula {
+  fn from(formula: &str) -> Formula {
+    Formula {
+      formula: formula.into(),
+      ..Default::default()
+    }
+  }
+}
+
+/// Options for limiting results returned.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormulaLimit {
+  /// Number of results to return.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub count: Option<i64>,
+  /// Direction of sort. Allowed enum values: `asc`,`desc`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub order: Option<SortOrder>,
+}
+
+/// Direction of sort.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SortOrder {
+  #[serde(rename = "asc")]
+  Ascending,
+  #[serde(rename = "desc")]
+  Descending,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for SortOrder {
+  fn default() -> SortOrder {
+    SortOrder::Descending
+  }
+}
+
+/// Define a display mode for the table cell.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CellDisplayMode {
+  #[serde(rename = "number")]
+  Number,
+  #[serde(rename = "bar")]
+  Bar,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for CellDisplayMode {
+  fn default() -> CellDisplayMode {
+    CellDisplayMode::Number
+  }
+}
+
+/// Comparator to apply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WidgetComparator {
+  #[serde(rename = "=")]
+  Equal,
+  #[serde(rename = ">")]
+  GreaterThan,
+  #[serde(rename = ">=")]
+  GreaterThanOrEqual,
+  #[serde(rename = "<")]
+  LessThan,
+  #[serde(rename = "<=")]
+  LessThanOrEqual,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for WidgetComparator {
+  fn default() -> WidgetComparator {
+    WidgetComparator::GreaterThan
+  }
+}
+
+/// Color palette to apply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionalFormatPalette {
+  #[serde(rename = "blue")]
+  Blue,
+  #[serde(rename = "custom_bg")]
+  CustomBackground,
+  #[serde(rename = "custom_image")]
+  CustomImage,
+  #[serde(rename = "custom_text")]
+  CustomText,
+  #[serde(rename = "gray_on_white")]
+  GrayOnWhite,
+  #[serde(rename = "grey")]
+  Grey,
+  #[serde(rename = "green")]
+  Green,
+  #[serde(rename = "orange")]
+  Orange,
+  #[serde(rename = "red")]
+  Red,
+  #[serde(rename = "red_on_white")]
+  RedOnWhite,
+  #[serde(rename = "white_on_gray")]
+  WhiteOnGray,
+  #[serde(rename = "white_on_green")]
+  WhiteOnGreen,
+  #[serde(rename = "green_on_white")]
+  GreenOnWhite,
+  #[serde(rename = "white_on_red")]
+  WhiteOnRed,
+  #[serde(rename = "white_on_yellow")]
+  WhiteOnYellow,
+  #[serde(rename = "yellow_on_white")]
+  YellowOnWhite,
+  #[serde(rename = "black_on_light_yellow")]
+  BlackOnLightYellow,
+  #[serde(rename = "black_on_light_green")]
+  BlackOnLightGreen,
+  #[serde(rename = "black_on_light_red")]
+  BlackOnLightRed,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ConditionalFormatPalette {
+  fn default() -> ConditionalFormatPalette {
+    ConditionalFormatPalette::WhiteOnRed
+  }
+}
+
+/// Define a conditional format for the widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConditionalFormat {
+  /// Comparator to apply. Allowed enum values: `=`,`>`,`>=`,`<`,`<=`
+  pub comparator: WidgetComparator,
+  /// Color palette to apply to the background, same values available as palette.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_bg_color: Option<String>,
+  /// Color palette to apply to the foreground, same values available as palette.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_fg_color: Option<String>,
+  /// True hides values.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hide_value: Option<bool>,
+  /// Displays an image as the background.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub image_url: Option<String>,
+  /// Metric from the request to correlate this conditional format with.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metric: Option<String>,
+  /// Color palette to apply.
+  pub palette: ConditionalFormatPalette,
+  /// Defines the displayed timeframe.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timeframe: Option<String>,
+  /// Value for the comparator.
+  pub value: f64,
+}
+impl ConditionalFormat {
+  /// Apply `palette` to values matching `comparator` `value`, e.g. `> 90`.
+  pub fn when(comparator: WidgetComparator, value: f64, palette: ConditionalFormatPalette) -> ConditionalFormat {
+    ConditionalFormat {
+      comparator,
+      palette,
+      value,
+      ..Default::default()
+    }
+  }
+  /// Custom background colour, with the `custom_bg` palette.
+  pub fn custom_bg_color(mut self, custom_bg_color: &str) -> ConditionalFormat {
+    self.palette = ConditionalFormatPalette::CustomBackground;
+    self.custom_bg_color = Some(custom_bg_color.into());
+    self
+  }
+  /// Custom text colour, with the `custom_text` palette.
+  pub fn custom_fg_color(mut self, custom_fg_color: &str) -> ConditionalFormat {
+    self.palette = ConditionalFormatPalette::CustomText;
+    self.custom_fg_color = Some(custom_fg_color.into());
+    self
+  }
+  pub fn hide_value(mut self, hide_value: bool) -> ConditionalFormat {
+    self.hide_value = Some(hide_value);
+    self
+  }
+  /// Background image, with the `custom_image` palette.
+  pub fn image_url(mut self, image_url: &str) -> ConditionalFormat {
+    self.palette = ConditionalFormatPalette::CustomImage;
+    self.image_url = Some(image_url.into());
+    self
+  }
+  pub fn metric(mut self, metric: &str) -> ConditionalFormat {
+    self.metric = Some(metric.into());
+    self
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -230,6 +490,7 @@ pub enum TickEdge {
   #[serde(rename = "top")]
   Top,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for TickEdge {
   fn default() -> TickEdge {
     TickEdge::Right
@@ -240,90 +501,3317 @@ impl Default for TickEdge {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Query {
-  Option1(QueryOption1),
+  Metrics(MetricsQuery),
+  Events(EventsQuery),
+  Process(ProcessQuery),
+  ApmDependencyStats(ApmDependencyStatsQuery),
+  ApmResourceStats(ApmResourceStatsQuery),
+  Slo(SloQuery),
+  CloudCost(CloudCostQuery),
+}
+impl Query {
+  /// A metrics query named `name`, e.g. `query1`, for use in formulas.
+  pub fn metrics(name: &str, query: &str) -> Query {
+    MetricsQuery::new(name, query).into()
+  }
+  /// Former variant for metrics queries, now [`Query::Metrics`].
+  #[deprecated(note = "Use `Query::Metrics`")]
+  #[allow(non_snake_case)]
+  pub fn Option1(query: MetricsQuery) -> Query {
+    Query::Metrics(query)
+  }
+}
+impl From<MetricsQuery> for Query {
+  fn from(query: MetricsQuery) -> Query {
+    Query::Metrics(query)
+  }
+}
+impl From<EventsQuery> for Query {
+  fn from(query: EventsQuery) -> Query {
+    Query::Events(query)
+  }
+}
+impl From<ProcessQuery> for Query {
+  fn from(query: ProcessQuery) -> Query {
+    Query::Process(query)
+  }
+}
+impl From<ApmDependencyStatsQuery> for Query {
+  fn from(query: ApmDependencyStatsQuery) -> Query {
+    Query::ApmDependencyStats(query)
+  }
+}
+impl From<ApmResourceStatsQuery> for Query {
+  fn from(query: ApmResourceStatsQuery) -> Query {
+    Query::ApmResourceStats(query)
+  }
+}
+impl From<SloQuery> for Query {
+  fn from(query: SloQuery) -> Query {
+    Query::Slo(query)
+  }
+}
+impl From<CloudCostQuery> for Query {
+  fn from(query: CloudCostQuery) -> Query {
+    Query::CloudCost(query)
+  }
 }
 
-/// A formula and functions metrics query.
+/// Data source for metrics queries.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct QueryOption1 {
+pub enum MetricsDataSource {
+  #[serde(rename = "metrics")]
+  Metrics,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for MetricsDataSource {
+  fn default() -> MetricsDataSource {
+    MetricsDataSource::Metrics
+  }
+}
+
+/// A formula and functions metrics query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsQuery {
   /// The aggregation methods available for metrics queries. Allowed enum values: `avg`,`min`,`max`,`sum`,`last`,`area`,`l2norm`,`percentile`
   #[serde(skip_serializing_if = "Option::is_none")]
   pub aggregator: Option<MetricAggregator>,
   /// Data source for metrics queries. Allowed enum values: `metrics`
-  pub data_source: String,
+  pub data_source: MetricsDataSource,
   /// Name of the query for use in formulas.
   pub name: String,
   /// Metrics query definition.
   pub query: String,
 }
+impl MetricsQuery {
+  pub fn new(name: &str, query: &str) -> MetricsQuery {
+    MetricsQuery {
+      name: name.into(),
+      query: query.into(),
+      ..Default::default()
+    }
+  }
+  /// Reduce the timeseries to a single value, for scalar requests.
+  pub fn aggregator(mut self, aggregator: MetricAggregator) -> MetricsQuery {
+    self.aggregator = Some(aggregator);
+    self
+  }
+}
 
-/// Timeseries or Scalar response.
+/// Former name of [`MetricsQuery`], whose `data_source` is now a [`MetricsDataSource`] rather than a `String`.
+#[deprecated(note = "Use `MetricsQuery` and `Query::Metrics`")]
+pub type QueryOption1 = MetricsQuery;
+
+/// Data source for event platform-based queries.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum ResponseFormat {
-  #[serde(rename = "timeseries")]
-  Timeseries,
-  #[serde(rename = "scalar")]
-  Scalar,
+pub enum EventsDataSource {
+  #[serde(rename = "logs")]
+  Logs,
+  #[serde(rename = "spans")]
+  Spans,
+  #[serde(rename = "network")]
+  Network,
+  #[serde(rename = "rum")]
+  Rum,
+  #[serde(rename = "security_signals")]
+  SecuritySignals,
+  #[serde(rename = "profiles")]
+  Profiles,
+  #[serde(rename = "audit")]
+  Audit,
+  #[serde(rename = "events")]
+  Events,
+  #[serde(rename = "ci_tests")]
+  CiTests,
+  #[serde(rename = "ci_pipelines")]
+  CiPipelines,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for EventsDataSource {
+  fn default() -> EventsDataSource {
+    EventsDataSource::Logs
+  }
 }
 
-/// Widget definition.
+/// Compute options.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsCompute {
+  /// Aggregation methods for event platform queries. Allowed enum values: `avg`,`cardinality`,`count`,`max`,`median`,`min`,`pc75`,`pc90`,`pc95`,`pc98`,`pc99`,`sum`
+  pub aggregation: String,
+  /// A time interval in milliseconds.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub interval: Option<i64>,
+  /// Measurable attribute to compute, e.g. `@duration`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metric: Option<String>,
+}
+
+/// Options for sorting group by results.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsGroupBySort {
+  /// Aggregation methods for event platform queries.
+  pub aggregation: String,
+  /// Metric used for sorting group by results.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metric: Option<String>,
+  /// Direction of sort. Allowed enum values: `asc`,`desc`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub order: Option<SortOrder>,
+}
+
+/// List of objects used to group by.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsGroupBy {
+  /// Event facet.
+  pub facet: String,
+  /// Number of groups to return.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<i64>,
+  /// Options for sorting group by results.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort: Option<EventsGroupBySort>,
+}
+
+/// Search options.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsSearch {
+  /// Events search string.
+  pub query: String,
+}
+
+/// A formula and functions events query, over logs, spans, RUM, security signals, profiles, audit or network data.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsQuery {
+  /// Compute options.
+  pub compute: EventsCompute,
+  /// Data source for event platform-based queries.
+  pub data_source: EventsDataSource,
+  /// Group by options.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub group_by: Option<Vec<EventsGroupBy>>,
+  /// An array of index names to query in the stream. Omit or use `[]` to query all indexes at once.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub indexes: Option<Vec<String>>,
+  /// Name of the query for use in formulas.
+  pub name: String,
+  /// Search options.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub search: Option<EventsSearch>,
+  /// Option for storage location. Feature in Private Beta.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub storage: Option<String>,
+}
+impl EventsQuery {
+  /// Count the events of `data_source`, see [`EventsQuery::compute`] for other aggregations.
+  pub fn new(data_source: EventsDataSource, name: &str) -> EventsQuery {
+    EventsQuery {
+      compute: EventsCompute {
+        aggregation: "count".into(),
+        ..Default::default()
+      },
+      data_source,
+      name: name.into(),
+      ..Default::default()
+    }
+  }
+  /// A logs query, see [`EventsQuery::new`].
+  pub fn logs(name: &str) -> EventsQuery {
+    EventsQuery::new(EventsDataSource::Logs, name)
+  }
+  /// A spans query, see [`EventsQuery::new`].
+  pub fn spans(name: &str) -> EventsQuery {
+    EventsQuery::new(EventsDataSource::Spans, name)
+  }
+  /// A RUM query, see [`EventsQuery::new`].
+  pub fn rum(name: &str) -> EventsQuery {
+    EventsQuery::new(EventsDataSource::Rum, name)
+  }
+  /// Search query, e.g. `service:web status:error`.
+  pub fn search(mut self, query: &str) -> EventsQuery {
+    self.search = Some(EventsSearch { query: query.into() });
+    self
+  }
+  /// Aggregate with `aggregation`, e.g. `avg`, over the `metric` attribute, e.g. `@duration`, or `None` for `count` and `cardinality`.
+  pub fn compute(mut self, aggregation: &str, metric: Option<&str>) -> EventsQuery {
+    self.compute = EventsCompute {
+      aggregation: aggregation.into(),
+      interval: None,
+      metric: metric.map(|metric| metric.into()),
+    };
+    self
+  }
+  /// Group by a facet, e.g. `service`, keeping the top `limit` groups.
+  pub fn group_by(mut self, facet: &str, limit: i64) -> EventsQuery {
+    self.group_by.get_or_insert_with(Vec::new).push(EventsGroupBy {
+      facet: facet.into(),
+      limit: Some(limit),
+      sort: None,
+    });
+    self
+  }
+  pub fn index(mut self, index: &str) -> EventsQuery {
+    self.indexes.get_or_insert_with(Vec::new).push(index.into());
+    self
+  }
+}
+
+/// Data sources that rely on the process backend.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Request {
-  /// Aggregator used for the request. Allowed enum values: `avg`,`last`,`max`,`min`,`sum`,`percentile`
+pub enum ProcessDataSource {
+  #[serde(rename = "process")]
+  Process,
+  #[serde(rename = "container")]
+  Container,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ProcessDataSource {
+  fn default() -> ProcessDataSource {
+    ProcessDataSource::Process
+  }
+}
+
+/// Process query using formulas and functions.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProcessQuery {
+  /// The aggregation methods available for metrics queries. Allowed enum values: `avg`,`min`,`max`,`sum`,`last`,`area`,`l2norm`,`percentile`
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub aggregator: Option<RequestAggregator>,
-  // apm_query
-  // audit_query
-  // conditional_formats
-  // event_query
-  /// List of formulas that operate on queries.
+  pub aggregator: Option<MetricAggregator>,
+  /// Data sources that rely on the process backend. Allowed enum values: `process`,`container`
+  pub data_source: ProcessDataSource,
+  /// Whether to normalize the CPU percentages.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub formulas: Option<Vec<Formula>>,
-  // log_query
-  // network_query
-  // process_query
-  // profile_metrics_query
-  // q
-  /// List of queries that can be returned directly or used in formulas.
+  pub is_normalized_cpu: Option<bool>,
+  /// Number of hits to return.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub queries: Option<Vec<Query>>,
-  /// Timeseries or Scalar response. Allowed enum values: `timeseries`,`scalar`
+  pub limit: Option<i64>,
+  /// Process metric name.
+  pub metric: String,
+  /// Name of query for use in formulas.
+  pub name: String,
+  /// Direction of sort. Allowed enum values: `asc`,`desc`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort: Option<SortOrder>,
+  /// An array of tags to filter by.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tag_filters: Option<Vec<String>>,
+  /// Text to use as filter.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub response_format: Option<ResponseFormat>
-  // rum_query
-  // security_query
+  pub text_filter: Option<String>,
+}
+impl ProcessQuery {
+  /// A process metric, e.g. `process.stat.cpu.total_pct`.
+  pub fn new(name: &str, metric: &str) -> ProcessQuery {
+    ProcessQuery {
+      metric: metric.into(),
+      name: name.into(),
+      ..Default::default()
+    }
+  }
+  pub fn data_source(mut self, data_source: ProcessDataSource) -> ProcessQuery {
+    self.data_source = data_source;
+    self
+  }
+  pub fn text_filter(mut self, text_filter: &str) -> ProcessQuery {
+    self.text_filter = Some(text_filter.into());
+    self
+  }
+  pub fn tag_filter(mut self, tag_filter: &str) -> ProcessQuery {
+    self.tag_filters.get_or_insert_with(Vec::new).push(tag_filter.into());
+    self
+  }
+  /// Only return the first `limit` processes in `sort` order.
+  pub fn limit(mut self, limit: i64, sort: SortOrder) -> ProcessQuery {
+    self.limit = Some(limit);
+    self.sort = Some(sort);
+    self
+  }
+  pub fn aggregator(mut self, aggregator: MetricAggregator) -> ProcessQuery {
+    self.aggregator = Some(aggregator);
+    self
+  }
 }
 
+/// Data source for APM dependency stats queries.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct QueryValue {
-  /// Whether to use auto-scaling or not.
+pub enum ApmDependencyStatsDataSource {
+  #[serde(rename = "apm_dependency_stats")]
+  ApmDependencyStats,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ApmDependencyStatsDataSource {
+  fn default() -> ApmDependencyStatsDataSource {
+    ApmDependencyStatsDataSource::ApmDependencyStats
+  }
+}
+
+/// A formula and functions APM dependency stats query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApmDependencyStatsQuery {
+  /// Data source for APM dependency stats queries. Allowed enum values: `apm_dependency_stats`
+  pub data_source: ApmDependencyStatsDataSource,
+  /// APM environment.
+  pub env: String,
+  /// Determines whether stats for upstream or downstream dependencies should be queried.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub autoscale: Option<bool>,
-  /// List of custom links.
+  pub is_upstream: Option<bool>,
+  /// Name of query to use in formulas.
+  pub name: String,
+  /// Name of operation on service.
+  pub operation_name: String,
+  /// The name of the second primary tag used within APM; required when `primary_tag_value` is specified.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub custom_links: Option<Vec<CustomLink>>,
-  /// Display a unit of your choice on the widget.
+  pub primary_tag_name: Option<String>,
+  /// Filter APM data by the second primary tag. `primary_tag_name` must also be specified.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub custom_unit: Option<String>,
-  /// Number of decimals to show. If not defined, the widget uses the raw value.
+  pub primary_tag_value: Option<String>,
+  /// APM resource.
+  pub resource_name: String,
+  /// APM service.
+  pub service: String,
+  /// APM statistic. Allowed enum values: `avg_duration`,`avg_root_duration`,`avg_spans_per_trace`,`error_rate`,`pct_exec_time`,`pct_of_traces`,`total_traces_count`
+  pub stat: String,
+}
+impl ApmDependencyStatsQuery {
+  /// The `stat`, e.g. `avg_duration`, of a resource of an operation of a service in an environment.
+  pub fn new(name: &str, env: &str, service: &str, operation_name: &str, resource_name: &str, stat: &str) -> ApmDependencyStatsQuery {
+    ApmDependencyStatsQuery {
+      env: env.into(),
+      name: name.into(),
+      operation_name: operation_name.into(),
+      resource_name: resource_name.into(),
+      service: service.into(),
+      stat: stat.into(),
+      ..Default::default()
+    }
+  }
+  pub fn is_upstream(mut self, is_upstream: bool) -> ApmDependencyStatsQuery {
+    self.is_upstream = Some(is_upstream);
+    self
+  }
+  pub fn primary_tag(mut self, primary_tag_name: &str, primary_tag_value: &str) -> ApmDependencyStatsQuery {
+    self.primary_tag_name = Some(primary_tag_name.into());
+    self.primary_tag_value = Some(primary_tag_value.into());
+    self
+  }
+}
+
+/// Data source for APM resource stats queries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ApmResourceStatsDataSource {
+  #[serde(rename = "apm_resource_stats")]
+  ApmResourceStats,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ApmResourceStatsDataSource {
+  fn default() -> ApmResourceStatsDataSource {
+    ApmResourceStatsDataSource::ApmResourceStats
+  }
+}
+
+/// APM resource stats query using formulas and functions.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApmResourceStatsQuery {
+  /// Data source for APM resource stats queries. Allowed enum values: `apm_resource_stats`
+  pub data_source: ApmResourceStatsDataSource,
+  /// APM environment.
+  pub env: String,
+  /// Array of fields to group results by.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub precision: Option<i64>,
-  /// Widget definition.
+  pub group_by: Option<Vec<String>>,
+  /// Name of this query to use in formulas.
+  pub name: String,
+  /// Name of operation on service.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub requests: Option<Vec<Request>>,
-  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  pub operation_name: Option<String>,
+  /// Name of the second primary tag used within APM. Required when `primary_tag_value` is specified.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub text_align: Option<TextAlign>,
-  /// Time setting for the widget.
+  pub primary_tag_name: Option<String>,
+  /// Value of the second primary tag by which to filter APM data. `primary_tag_name` must also be specified.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub time: Option<Time>,
-  /// Title of your widget.
+  pub primary_tag_value: Option<String>,
+  /// APM resource name.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub title: Option<String>,
-  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  pub resource_name: Option<String>,
+  /// APM service name.
+  pub service: String,
+  /// APM resource stat name. Allowed enum values: `errors`,`error_rate`,`hits`,`latency_avg`,`latency_distribution`,`latency_max`,`latency_p50`,`latency_p75`,`latency_p90`,`latency_p95`,`latency_p99`
+  pub stat: String,
+}
+impl ApmResourceStatsQuery {
+  /// The `stat`, e.g. `hits` or `latency_p99`, of a service in an environment.
+  pub fn new(name: &str, env: &str, service: &str, stat: &str) -> ApmResourceStatsQuery {
+    ApmResourceStatsQuery {
+      env: env.into(),
+      name: name.into(),
+      service: service.into(),
+      stat: stat.into(),
+      ..Default::default()
+    }
+  }
+  pub fn operation_name(mut self, operation_name: &str) -> ApmResourceStatsQuery {
+    self.operation_name = Some(operation_name.into());
+    self
+  }
+  pub fn resource_name(mut self, resource_name: &str) -> ApmResourceStatsQuery {
+    self.resource_name = Some(resource_name.into());
+    self
+  }
+  pub fn group_by(mut self, group_by: Vec<String>) -> ApmResourceStatsQuery {
+    self.group_by = Some(group_by);
+    self
+  }
+  pub fn primary_tag(mut self, primary_tag_name: &str, primary_tag_value: &str) -> ApmResourceStatsQuery {
+    self.primary_tag_name = Some(primary_tag_name.into());
+    self.primary_tag_value = Some(primary_tag_value.into());
+    self
+  }
+}
+
+/// Data source for SLO measures queries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SloDataSource {
+  #[serde(rename = "slo")]
+  Slo,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for SloDataSource {
+  fn default() -> SloDataSource {
+    SloDataSource::Slo
+  }
+}
+
+/// A formula and functions SLO query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SloQuery {
+  /// Additional filters applied to the SLO query.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub title_align: Option<TextAlign>,
-  /// Size of the title.
+  pub additional_query_filters: Option<String>,
+  /// Data source for SLO measures queries. Allowed enum values: `slo`
+  pub data_source: SloDataSource,
+  /// Group mode to query measures. Allowed enum values: `overall`,`components`
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub title_size: Option<String>,
-}
\ No newline at end of file
+  pub group_mode: Option<String>,
+  /// SLO measures queries. Allowed enum values: `good_events`,`bad_events`,`slo_status`,`error_budget_remaining`,`burn_rate`,`error_budget_burndown`
+  pub measure: String,
+  /// Name of the query for use in formulas.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// ID of an SLO to query measures.
+  pub slo_id: String,
+  /// Type of the SLO to query. Allowed enum values: `metric`,`monitor`,`time_slice`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub slo_query_type: Option<String>,
+}
+impl SloQuery {
+  /// The `measure`, e.g. `slo_status` or `burn_rate`, of an SLO.
+  pub fn new(name: &str, slo_id: &str, measure: &str) -> SloQuery {
+    SloQuery {
+      measure: measure.into(),
+      name: Some(name.into()),
+      slo_id: slo_id.into(),
+      ..Default::default()
+    }
+  }
+  pub fn group_mode(mut self, group_mode: &str) -> SloQuery {
+    self.group_mode = Some(group_mode.into());
+    self
+  }
+  pub fn slo_query_type(mut self, slo_query_type: &str) -> SloQuery {
+    self.slo_query_type = Some(slo_query_type.into());
+    self
+  }
+  pub fn additional_query_filters(mut self, additional_query_filters: &str) -> SloQuery {
+    self.additional_query_filters = Some(additional_query_filters.into());
+    self
+  }
+}
+
+/// Data source for Cloud Cost queries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CloudCostDataSource {
+  #[serde(rename = "cloud_cost")]
+  CloudCost,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for CloudCostDataSource {
+  fn default() -> CloudCostDataSource {
+    CloudCostDataSource::CloudCost
+  }
+}
+
+/// A formula and functions Cloud Cost query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CloudCostQuery {
+  /// Aggregator used for the request. Allowed enum values: `avg`,`last`,`max`,`min`,`sum`,`percentile`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aggregator: Option<RequestAggregator>,
+  /// Data source for Cloud Cost queries. Allowed enum values: `cloud_cost`
+  pub data_source: CloudCostDataSource,
+  /// Name of the query for use in formulas.
+  pub name: String,
+  /// Query for Cloud Cost data.
+  pub query: String,
+}
+impl CloudCostQuery {
+  /// A Cloud Cost query, e.g. `sum:aws.cost.amortized{*} by {aws_product}`.
+  pub fn new(name: &str, query: &str) -> CloudCostQuery {
+    CloudCostQuery {
+      name: name.into(),
+      query: query.into(),
+      ..Default::default()
+    }
+  }
+  pub fn aggregator(mut self, aggregator: RequestAggregator) -> CloudCostQuery {
+    self.aggregator = Some(aggregator);
+    self
+  }
+}
+
+/// Define computation for a log query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogQueryCompute {
+  /// The aggregation method.
+  pub aggregation: String,
+  /// Facet name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub facet: Option<String>,
+  /// Define a time interval in seconds.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub interval: Option<i64>,
+}
+
+/// Define a sorting method.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogQueryGroupBySort {
+  /// The aggregation method.
+  pub aggregation: String,
+  /// Facet name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub facet: Option<String>,
+  /// Widget sorting methods. Allowed enum values: `asc`,`desc`
+  pub order: SortOrder,
+}
+
+/// Defined items in the group.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogQueryGroupBy {
+  /// Facet name.
+  pub facet: String,
+  /// Maximum number of items in the group.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<i64>,
+  /// Define a sorting method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort: Option<LogQueryGroupBySort>,
+}
+
+/// The query being made on the logs.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogQuerySearch {
+  /// Search value to apply.
+  pub query: String,
+}
+
+/// The log query, for widgets not using formulas and functions. Also used for APM, events, network, RUM, security and
+/// profiles queries.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogQueryDefinition {
+  /// Define computation for a log query.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub compute: Option<LogQueryCompute>,
+  /// List of tag prefixes to group by in the case of a cluster check.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub group_by: Option<Vec<LogQueryGroupBy>>,
+  /// A coma separated-list of index names. Use `*` to query all indexes at once.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub index: Option<String>,
+  /// This field is mutually exclusive with `compute`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub multi_compute: Option<Vec<LogQueryCompute>>,
+  /// The query being made on the logs.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub search: Option<LogQuerySearch>,
+}
+impl LogQueryDefinition {
+  /// Search query, e.g. `service:web status:error`.
+  pub fn search(mut self, query: &str) -> LogQueryDefinition {
+    self.search = Some(LogQuerySearch { query: query.into() });
+    self
+  }
+  /// Aggregate with `aggregation`, e.g. `avg`, over `facet`, e.g. `@duration`, or `None` for `count`.
+  pub fn compute(mut self, aggregation: &str, facet: Option<&str>) -> LogQueryDefinition {
+    self.compute = Some(LogQueryCompute {
+      aggregation: aggregation.into(),
+      facet: facet.map(|facet| facet.into()),
+      interval: None,
+    });
+    self
+  }
+  /// Group by a facet, e.g. `service`, keeping the top `limit` groups.
+  pub fn group_by(mut self, facet: &str, limit: i64) -> LogQueryDefinition {
+    self.group_by.get_or_insert_with(Vec::new).push(LogQueryGroupBy {
+      facet: facet.into(),
+      limit: Some(limit),
+      sort: None,
+    });
+    self
+  }
+  pub fn index(mut self, index: &str) -> LogQueryDefinition {
+    self.index = Some(index.into());
+    self
+  }
+}
+
+/// The process query, for widgets not using formulas and functions.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProcessQueryDefinition {
+  /// List of processes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub filter_by: Option<Vec<String>>,
+  /// Max number of items in the filter list.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<i64>,
+  /// Your chosen metric.
+  pub metric: String,
+  /// Your chosen search term.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub search_by: Option<String>,
+}
+
+/// Timeseries or Scalar response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ResponseFormat {
+  #[serde(rename = "timeseries")]
+  Timeseries,
+  #[serde(rename = "scalar")]
+  Scalar,
+}
+
+/// Widget definition.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Request {
+  /// Aggregator used for the request. Allowed enum values: `avg`,`last`,`max`,`min`,`sum`,`percentile`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aggregator: Option<RequestAggregator>,
+  /// The APM query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub apm_query: Option<LogQueryDefinition>,
+  /// The audit query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub audit_query: Option<LogQueryDefinition>,
+  /// List of conditional formats.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub conditional_formats: Option<Vec<ConditionalFormat>>,
+  /// The event query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub event_query: Option<LogQueryDefinition>,
+  /// List of formulas that operate on queries.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub formulas: Option<Vec<Formula>>,
+  /// The log query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub log_query: Option<LogQueryDefinition>,
+  /// The network query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub network_query: Option<LogQueryDefinition>,
+  /// The process query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub process_query: Option<ProcessQueryDefinition>,
+  /// The profile metrics query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub profile_metrics_query: Option<LogQueryDefinition>,
+  /// Metric query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub q: Option<String>,
+  /// List of queries that can be returned directly or used in formulas.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub queries: Option<Vec<Query>>,
+  /// Timeseries or Scalar response. Allowed enum values: `timeseries`,`scalar`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub response_format: Option<ResponseFormat>,
+  /// The RUM query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub rum_query: Option<LogQueryDefinition>,
+  /// The security query, for widgets not using formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub security_query: Option<LogQueryDefinition>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Request {
+  pub fn aggregator(mut self, aggregator: RequestAggregator) -> Request {
+    self.aggregator = Some(aggregator);
+    self
+  }
+  pub fn q(mut self, q: &str) -> Request {
+    self.q = Some(q.into());
+    self
+  }
+  pub fn apm_query(mut self, apm_query: LogQueryDefinition) -> Request {
+    self.apm_query = Some(apm_query);
+    self
+  }
+  pub fn log_query(mut self, log_query: LogQueryDefinition) -> Request {
+    self.log_query = Some(log_query);
+    self
+  }
+  pub fn process_query(mut self, process_query: ProcessQueryDefinition) -> Request {
+    self.process_query = Some(process_query);
+    self
+  }
+  pub fn conditional_format(mut self, conditional_format: ConditionalFormat) -> Request {
+    self.conditional_formats.get_or_insert_with(Vec::new).push(conditional_format);
+    self
+  }
+  /// Add a formula, either an expression such as `query1 / query2` or a [`Formula`].
+  pub fn formula<F: Into<Formula>>(mut self, formula: F) -> Request {
+    self.formulas.get_or_insert_with(Vec::new).push(formula.into());
+    self
+  }
+  /// Add a formula and functions query, either a [`Query`] or one of its variants such as [`EventsQuery`].
+  pub fn query<Q: Into<Query>>(mut self, query: Q) -> Request {
+    self.queries.get_or_insert_with(Vec::new).push(query.into());
+    self
+  }
+  pub fn response_format(mut self, response_format: ResponseFormat) -> Request {
+    self.response_format = Some(response_format);
+    self
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryValue {
+  /// Whether to use auto-scaling or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub autoscale: Option<bool>,
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Display a unit of your choice on the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_unit: Option<String>,
+  /// Number of decimals to show. If not defined, the widget uses the raw value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub precision: Option<i64>,
+  /// Widget definition.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requests: Option<Vec<Request>>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub text_align: Option<TextAlign>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+
+/// How to align the legend, or where to show it, on timeseries widgets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LegendLayout {
+  #[serde(rename = "auto")]
+  Auto,
+  #[serde(rename = "horizontal")]
+  Horizontal,
+  #[serde(rename = "vertical")]
+  Vertical,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for LegendLayout {
+  fn default() -> LegendLayout {
+    LegendLayout::Auto
+  }
+}
+
+/// Columns displayed in the legend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LegendColumn {
+  #[serde(rename = "value")]
+  Value,
+  #[serde(rename = "avg")]
+  Average,
+  #[serde(rename = "sum")]
+  Sum,
+  #[serde(rename = "min")]
+  Minimum,
+  #[serde(rename = "max")]
+  Maximum,
+}
+
+/// Type of display to use for a timeseries request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DisplayType {
+  #[serde(rename = "area")]
+  Area,
+  #[serde(rename = "bars")]
+  Bars,
+  #[serde(rename = "line")]
+  Line,
+  #[serde(rename = "overlay")]
+  Overlay,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for DisplayType {
+  fn default() -> DisplayType {
+    DisplayType::Line
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LineType {
+  #[serde(rename = "dashed")]
+  Dashed,
+  #[serde(rename = "dotted")]
+  Dotted,
+  #[serde(rename = "solid")]
+  Solid,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for LineType {
+  fn default() -> LineType {
+    LineType::Solid
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LineWidth {
+  #[serde(rename = "normal")]
+  Normal,
+  #[serde(rename = "thick")]
+  Thick,
+  #[serde(rename = "thin")]
+  Thin,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for LineWidth {
+  fn default() -> LineWidth {
+    LineWidth::Normal
+  }
+}
+
+/// Define request widget style.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestStyle {
+  /// Type of lines displayed. Allowed enum values: `dashed`,`dotted`,`solid`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub line_type: Option<LineType>,
+  /// Width of line displayed. Allowed enum values: `normal`,`thick`,`thin`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub line_width: Option<LineWidth>,
+  /// Color palette to apply to the widget, e.g. `dog_classic`, `cool`, `warm`, `purple`, `orange` or `gray`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub palette: Option<String>,
+}
+impl RequestStyle {
+  pub fn line_type(mut self, line_type: LineType) -> RequestStyle {
+    self.line_type = Some(line_type);
+    self
+  }
+  pub fn line_width(mut self, line_width: LineWidth) -> RequestStyle {
+    self.line_width = Some(line_width);
+    self
+  }
+  pub fn palette(mut self, palette: &str) -> RequestStyle {
+    self.palette = Some(palette.into());
+    self
+  }
+}
+
+/// Used to define expression aliases.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestMetadata {
+  /// Expression alias.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alias_name: Option<String>,
+  /// Expression name.
+  pub expression: String,
+}
+
+/// Axis controls for the widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Axis {
+  /// Set to `true` to include zero.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub include_zero: Option<bool>,
+  /// The label of the axis to display on the graph. Only usable on Scatterplot Widgets.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  /// Specifies maximum numeric value to show on the axis. Defaults to `auto`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max: Option<String>,
+  /// Specifies minimum numeric value to show on the axis. Defaults to `auto`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub min: Option<String>,
+  /// Specifies the scale type. Possible values are `linear`, `log`, `sqrt`, and `pow##` (for example `pow2` or `pow0.5`).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub scale: Option<String>,
+}
+impl Axis {
+  pub fn include_zero(mut self, include_zero: bool) -> Axis {
+    self.include_zero = Some(include_zero);
+    self
+  }
+  pub fn label(mut self, label: &str) -> Axis {
+    self.label = Some(label.into());
+    self
+  }
+  pub fn max(mut self, max: &str) -> Axis {
+    self.max = Some(max.into());
+    self
+  }
+  pub fn min(mut self, min: &str) -> Axis {
+    self.min = Some(min.into());
+    self
+  }
+  pub fn scale(mut self, scale: &str) -> Axis {
+    self.scale = Some(scale.into());
+    self
+  }
+}
+
+/// Markers allow you to add visual conditional formatting for your graphs.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Marker {
+  /// Combination of severity (`error`, `warning`, `ok`, `info`) and line type (`dashed`, `solid`, `bold`), e.g. `error dashed`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_type: Option<String>,
+  /// Label to display over the marker.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  /// Timestamp for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<String>,
+  /// Value to apply. Can be a single value `y = 15` or a range of values `0 < y < 10`.
+  pub value: String,
+}
+impl Marker {
+  pub fn display_type(mut self, display_type: &str) -> Marker {
+    self.display_type = Some(display_type.into());
+    self
+  }
+  pub fn label(mut self, label: &str) -> Marker {
+    self.label = Some(label.into());
+    self
+  }
+  pub fn value(mut self, value: &str) -> Marker {
+    self.value = value.into();
+    self
+  }
+}
+
+/// Event overlay on the widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WidgetEvent {
+  /// Query definition.
+  pub q: String,
+  /// The execution method for multi-value filters.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags_execution: Option<String>,
+}
+
+/// Updated timeseries widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeSeriesRequest {
+  #[serde(flatten)]
+  pub request: Request,
+  /// Type of display to use for the request. Allowed enum values: `area`,`bars`,`line`,`overlay`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_type: Option<DisplayType>,
+  /// Used to define expression aliases.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<Vec<RequestMetadata>>,
+  /// Whether or not to display a second y-axis on the right.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub on_right_yaxis: Option<bool>,
+  /// Define request widget style.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub style: Option<RequestStyle>,
+}
+impl TimeSeriesRequest {
+  /// A line graph of a single metrics query, e.g. `avg:system.cpu.user{*} by {host}`.
+  pub fn metrics(query: &str) -> TimeSeriesRequest {
+    TimeSeriesRequest {
+      request: Request::default()
+        .query(Query::metrics("query1", query))
+        .formula("query1")
+        .response_format(ResponseFormat::Timeseries),
+      display_type: Some(DisplayType::Line),
+      ..Default::default()
+    }
+  }
+  pub fn display_type(mut self, display_type: DisplayType) -> TimeSeriesRequest {
+    self.display_type = Some(display_type);
+    self
+  }
+  /// Alias the expression, e.g. `query1`, in the legend.
+  pub fn alias(mut self, expression: &str, alias_name: &str) -> TimeSeriesRequest {
+    self.metadata.get_or_insert_with(Vec::new).push(RequestMetadata {
+      alias_name: Some(alias_name.into()),
+      expression: expression.into(),
+    });
+    self
+  }
+  pub fn on_right_yaxis(mut self, on_right_yaxis: bool) -> TimeSeriesRequest {
+    self.on_right_yaxis = Some(on_right_yaxis);
+    self
+  }
+  pub fn style(mut self, style: RequestStyle) -> TimeSeriesRequest {
+    self.style = Some(style);
+    self
+  }
+}
+
+/// The timeseries visualization allows you to display the evolution of one or more metrics, log events, or Indexed Spans over time.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeSeries {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// List of widget events.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub events: Option<Vec<WidgetEvent>>,
+  /// Columns displayed in the legend. Allowed enum values: `value`,`avg`,`sum`,`min`,`max`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub legend_columns: Option<Vec<LegendColumn>>,
+  /// Layout of the legend. Allowed enum values: `auto`,`horizontal`,`vertical`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub legend_layout: Option<LegendLayout>,
+  /// Available legend sizes for a widget. Should be one of `0`, `2`, `4`, `8`, `16`, or `auto`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub legend_size: Option<String>,
+  /// List of markers.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub markers: Option<Vec<Marker>>,
+  /// List of timeseries widget requests.
+  pub requests: Vec<TimeSeriesRequest>,
+  /// Axis controls for the right y-axis.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub right_yaxis: Option<Axis>,
+  /// Whether or not to display the legend on this widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_legend: Option<bool>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Axis controls for the y-axis.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub yaxis: Option<Axis>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl TimeSeries {
+  pub fn title(mut self, title: &str) -> TimeSeries {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: TimeSeriesRequest) -> TimeSeries {
+    self.requests.push(request);
+    self
+  }
+  /// Add a line graph of a metrics query, see [`TimeSeriesRequest::metrics`].
+  pub fn metrics(self, query: &str) -> TimeSeries {
+    self.request(TimeSeriesRequest::metrics(query))
+  }
+  pub fn yaxis(mut self, yaxis: Axis) -> TimeSeries {
+    self.yaxis = Some(yaxis);
+    self
+  }
+  pub fn right_yaxis(mut self, right_yaxis: Axis) -> TimeSeries {
+    self.right_yaxis = Some(right_yaxis);
+    self
+  }
+  pub fn marker(mut self, marker: Marker) -> TimeSeries {
+    self.markers.get_or_insert_with(Vec::new).push(marker);
+    self
+  }
+  /// Overlay events matching the query, e.g. `sources:deploy`.
+  pub fn event(mut self, q: &str) -> TimeSeries {
+    self.events.get_or_insert_with(Vec::new).push(WidgetEvent {
+      q: q.into(),
+      tags_execution: None,
+    });
+    self
+  }
+  pub fn legend_layout(mut self, legend_layout: LegendLayout) -> TimeSeries {
+    self.legend_layout = Some(legend_layout);
+    self
+  }
+  pub fn legend_columns(mut self, legend_columns: Vec<LegendColumn>) -> TimeSeries {
+    self.legend_columns = Some(legend_columns);
+    self
+  }
+  pub fn show_legend(mut self, show_legend: bool) -> TimeSeries {
+    self.show_legend = Some(show_legend);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> TimeSeries {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+  pub fn custom_link(mut self, custom_link: CustomLink) -> TimeSeries {
+    self.custom_links.get_or_insert_with(Vec::new).push(custom_link);
+    self
+  }
+}
+
+/// Updated top list widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TopListRequest {
+  #[serde(flatten)]
+  pub request: Request,
+  /// Define request widget style.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub style: Option<RequestStyle>,
+}
+impl TopListRequest {
+  /// The `limit` largest groups of a metrics query, e.g. `avg:system.cpu.user{*} by {host}`.
+  pub fn metrics(query: &str, limit: i64) -> TopListRequest {
+    TopListRequest {
+      request: Request::default()
+        .query(Query::metrics("query1", query))
+        .formula(Formula::from("query1").limit(limit, SortOrder::Descending))
+        .response_format(ResponseFormat::Scalar),
+      style: None,
+    }
+  }
+  pub fn conditional_format(mut self, conditional_format: ConditionalFormat) -> TopListRequest {
+    self.request = self.request.conditional_format(conditional_format);
+    self
+  }
+  pub fn style(mut self, style: RequestStyle) -> TopListRequest {
+    self.style = Some(style);
+    self
+  }
+}
+
+/// The top list visualization enables you to display a list of Tag value like hostname or service with the most or least of any metric value.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TopList {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// List of top list widget requests.
+  pub requests: Vec<TopListRequest>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl TopList {
+  pub fn title(mut self, title: &str) -> TopList {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: TopListRequest) -> TopList {
+    self.requests.push(request);
+    self
+  }
+  /// Add the `limit` largest groups of a metrics query, see [`TopListRequest::metrics`].
+  pub fn metrics(self, query: &str, limit: i64) -> TopList {
+    self.request(TopListRequest::metrics(query, limit))
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> TopList {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+  pub fn custom_link(mut self, custom_link: CustomLink) -> TopList {
+    self.custom_links.get_or_insert_with(Vec::new).push(custom_link);
+    self
+  }
+}
+
+/// Controls the display of the search bar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HasSearchBar {
+  #[serde(rename = "always")]
+  Always,
+  #[serde(rename = "never")]
+  Never,
+  #[serde(rename = "auto")]
+  Auto,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for HasSearchBar {
+  fn default() -> HasSearchBar {
+    HasSearchBar::Auto
+  }
+}
+
+/// Updated table widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableRequest {
+  #[serde(flatten)]
+  pub request: Request,
+  /// The column name (defaults to the metric name).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alias: Option<String>,
+  /// A list of display modes for each table cell. Allowed enum values: `number`,`bar`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cell_display_mode: Option<Vec<CellDisplayMode>>,
+  /// For metric queries, the number of lines to show in the table. Only one request should have this property.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<i64>,
+  /// Widget sorting methods. Allowed enum values: `asc`,`desc`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub order: Option<SortOrder>,
+}
+impl TableRequest {
+  /// One row per group of a metrics query, e.g. `avg:system.cpu.user{*} by {host}`, largest first.
+  pub fn metrics(query: &str, alias: &str, limit: i64) -> TableRequest {
+    TableRequest {
+      request: Request::default()
+        .query(Query::metrics("query1", query))
+        .formula(Formula::from("query1").alias(alias).limit(limit, SortOrder::Descending))
+        .response_format(ResponseFormat::Scalar),
+      ..Default::default()
+    }
+  }
+  /// Add a column from a formula over the queries of the request.
+  pub fn formula<F: Into<Formula>>(mut self, formula: F) -> TableRequest {
+    self.request = self.request.formula(formula);
+    self
+  }
+  pub fn query<Q: Into<Query>>(mut self, query: Q) -> TableRequest {
+    self.request = self.request.query(query);
+    self
+  }
+}
+
+/// The table visualization is available on dashboards. It displays columns of metrics grouped by tag key.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Table {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Controls the display of the search bar. Allowed enum values: `always`,`never`,`auto`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub has_search_bar: Option<HasSearchBar>,
+  /// Widget definition.
+  pub requests: Vec<TableRequest>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Table {
+  pub fn title(mut self, title: &str) -> Table {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: TableRequest) -> Table {
+    self.requests.push(request);
+    self
+  }
+  pub fn has_search_bar(mut self, has_search_bar: HasSearchBar) -> Table {
+    self.has_search_bar = Some(has_search_bar);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> Table {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+  pub fn custom_link(mut self, custom_link: CustomLink) -> Table {
+    self.custom_links.get_or_insert_with(Vec::new).push(custom_link);
+    self
+  }
+}
+
+/// Show the absolute or the relative change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ChangeType {
+  #[serde(rename = "absolute")]
+  Absolute,
+  #[serde(rename = "relative")]
+  Relative,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ChangeType {
+  fn default() -> ChangeType {
+    ChangeType::Absolute
+  }
+}
+
+/// Timeframe used for the change comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CompareTo {
+  #[serde(rename = "hour_before")]
+  HourBefore,
+  #[serde(rename = "day_before")]
+  DayBefore,
+  #[serde(rename = "week_before")]
+  WeekBefore,
+  #[serde(rename = "month_before")]
+  MonthBefore,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for CompareTo {
+  fn default() -> CompareTo {
+    CompareTo::DayBefore
+  }
+}
+
+/// What to order by.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ChangeOrderBy {
+  #[serde(rename = "change")]
+  Change,
+  #[serde(rename = "name")]
+  Name,
+  #[serde(rename = "present")]
+  Present,
+  #[serde(rename = "past")]
+  Past,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ChangeOrderBy {
+  fn default() -> ChangeOrderBy {
+    ChangeOrderBy::Change
+  }
+}
+
+/// Updated change widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangeRequest {
+  #[serde(flatten)]
+  pub request: Request,
+  /// Show the absolute or the relative change. Allowed enum values: `absolute`,`relative`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub change_type: Option<ChangeType>,
+  /// Timeframe used for the change comparison. Allowed enum values: `hour_before`,`day_before`,`week_before`,`month_before`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub compare_to: Option<CompareTo>,
+  /// Whether to show increase as good.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub increase_good: Option<bool>,
+  /// What to order by. Allowed enum values: `change`,`name`,`present`,`past`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub order_by: Option<ChangeOrderBy>,
+  /// Widget sorting methods. Allowed enum values: `asc`,`desc`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub order_dir: Option<SortOrder>,
+  /// Whether to show the present value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_present: Option<bool>,
+}
+impl ChangeRequest {
+  /// The change of a metrics query, e.g. `sum:app.requests{*} by {service}`, compared to `compare_to`.
+  pub fn metrics(query: &str, compare_to: CompareTo) -> ChangeRequest {
+    ChangeRequest {
+      request: Request::default()
+        .query(Query::metrics("query1", query))
+        .formula("query1")
+        .response_format(ResponseFormat::Scalar),
+      compare_to: Some(compare_to),
+      ..Default::default()
+    }
+  }
+  pub fn change_type(mut self, change_type: ChangeType) -> ChangeRequest {
+    self.change_type = Some(change_type);
+    self
+  }
+  pub fn increase_good(mut self, increase_good: bool) -> ChangeRequest {
+    self.increase_good = Some(increase_good);
+    self
+  }
+  pub fn order(mut self, order_by: ChangeOrderBy, order_dir: SortOrder) -> ChangeRequest {
+    self.order_by = Some(order_by);
+    self.order_dir = Some(order_dir);
+    self
+  }
+  pub fn show_present(mut self, show_present: bool) -> ChangeRequest {
+    self.show_present = Some(show_present);
+    self
+  }
+}
+
+/// The change graph shows you the change in a value over the time period chosen.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Change {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Array of one request object to display in the widget.
+  pub requests: Vec<ChangeRequest>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Change {
+  pub fn title(mut self, title: &str) -> Change {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: ChangeRequest) -> Change {
+    self.requests.push(request);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> Change {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+  pub fn custom_link(mut self, custom_link: CustomLink) -> Change {
+    self.custom_links.get_or_insert_with(Vec::new).push(custom_link);
+    self
+  }
+}
+
+/// The group widget allows you to keep similar graphs together on your dashboard.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Group {
+  /// Background color of the group title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub background_color: Option<String>,
+  /// URL of image to display as a banner for the group.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub banner_img: Option<String>,
+  /// Layout type of the group. Allowed enum values: `ordered`
+  pub layout_type: LayoutType,
+  /// Whether to show the title or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_title: Option<bool>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// List of widget groups.
+  pub widgets: Vec<Widget>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Group {
+  pub fn title(mut self, title: &str) -> Group {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn background_color(mut self, background_color: &str) -> Group {
+    self.background_color = Some(background_color.into());
+    self
+  }
+  pub fn banner_img(mut self, banner_img: &str) -> Group {
+    self.banner_img = Some(banner_img.into());
+    self
+  }
+  pub fn layout_type(mut self, layout_type: LayoutType) -> Group {
+    self.layout_type = layout_type;
+    self
+  }
+  pub fn show_title(mut self, show_title: bool) -> Group {
+    self.show_title = Some(show_title);
+    self
+  }
+  /// Add a widget, or a widget definition, to the group.
+  pub fn widget<W: Into<Widget>>(mut self, widget: W) -> Group {
+    self.widgets.push(widget.into());
+    self
+  }
+  pub fn widgets(mut self, widgets: Vec<Widget>) -> Group {
+    self.widgets = widgets;
+    self
+  }
+}
+
+/// Updated heat map widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeatMapRequest {
+  #[serde(flatten)]
+  pub request: Request,
+  /// Widget style definition.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub style: Option<RequestStyle>,
+}
+impl HeatMapRequest {
+  /// The distribution over time of a metrics query, e.g. `avg:system.cpu.user{*} by {host}`.
+  pub fn metrics(query: &str) -> HeatMapRequest {
+    HeatMapRequest {
+      request: Request::default()
+        .query(Query::metrics("query1", query))
+        .formula("query1")
+        .response_format(ResponseFormat::Timeseries),
+      style: None,
+    }
+  }
+  pub fn palette(mut self, palette: &str) -> HeatMapRequest {
+    self.style = Some(RequestStyle::default().palette(palette));
+    self
+  }
+}
+
+/// The heat map visualization shows metrics aggregated across many tags, such as hosts. The more hosts that have a particular value, the darker that square is.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeatMap {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// List of widget events.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub events: Option<Vec<WidgetEvent>>,
+  /// Available legend sizes for a widget. Should be one of `0`, `2`, `4`, `8`, `16`, or `auto`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub legend_size: Option<String>,
+  /// List of widget types.
+  pub requests: Vec<HeatMapRequest>,
+  /// Whether or not to display the legend on this widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_legend: Option<bool>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Axis controls for the y-axis.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub yaxis: Option<Axis>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl HeatMap {
+  pub fn title(mut self, title: &str) -> HeatMap {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: HeatMapRequest) -> HeatMap {
+    self.requests.push(request);
+    self
+  }
+  /// Add the distribution of a metrics query, see [`HeatMapRequest::metrics`].
+  pub fn metrics(self, query: &str) -> HeatMap {
+    self.request(HeatMapRequest::metrics(query))
+  }
+  pub fn yaxis(mut self, yaxis: Axis) -> HeatMap {
+    self.yaxis = Some(yaxis);
+    self
+  }
+  pub fn show_legend(mut self, show_legend: bool) -> HeatMap {
+    self.show_legend = Some(show_legend);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> HeatMap {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// The level of detail of APM stats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ApmStatsRowType {
+  #[serde(rename = "service")]
+  Service,
+  #[serde(rename = "resource")]
+  Resource,
+  #[serde(rename = "span")]
+  Span,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ApmStatsRowType {
+  fn default() -> ApmStatsRowType {
+    ApmStatsRowType::Service
+  }
+}
+
+/// Column properties.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApmStatsColumn {
+  /// A user-assigned alias for the column.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alias: Option<String>,
+  /// Define a display mode for the table cell. Allowed enum values: `number`,`bar`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cell_display_mode: Option<CellDisplayMode>,
+  /// Column name.
+  pub name: String,
+  /// Widget sorting methods. Allowed enum values: `asc`,`desc`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub order: Option<SortOrder>,
+}
+
+/// The APM stats query for table and distributions widgets.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApmStatsQuery {
+  /// Column properties used by the front end for display.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub columns: Option<Vec<ApmStatsColumn>>,
+  /// Environment name.
+  pub env: String,
+  /// Operation name associated with service.
+  pub name: String,
+  /// The organization's host group name and value.
+  pub primary_tag: String,
+  /// Resource name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub resource: Option<String>,
+  /// The level of detail for the request. Allowed enum values: `service`,`resource`,`span`
+  pub row_type: ApmStatsRowType,
+  /// Service name.
+  pub service: String,
+}
+
+/// Request type for distribution widgets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DistributionRequestType {
+  #[serde(rename = "histogram")]
+  Histogram,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for DistributionRequestType {
+  fn default() -> DistributionRequestType {
+    DistributionRequestType::Histogram
+  }
+}
+
+/// Updated distribution widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DistributionRequest {
+  #[serde(flatten)]
+  pub request: Request,
+  /// The APM stats query for table and distributions widgets.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub apm_stats_query: Option<ApmStatsQuery>,
+  /// Query definition for distribution widget histogram requests.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub query: Option<Query>,
+  /// Request type for the histogram request. Allowed enum values: `histogram`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub request_type: Option<DistributionRequestType>,
+  /// Widget style definition.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub style: Option<RequestStyle>,
+}
+impl DistributionRequest {
+  /// A histogram of a query, e.g. a distribution metric such as `avg:trace.http.request{*}`.
+  pub fn histogram(query: Query) -> DistributionRequest {
+    DistributionRequest {
+      query: Some(query),
+      request_type: Some(DistributionRequestType::Histogram),
+      ..Default::default()
+    }
+  }
+  /// A histogram of a metrics query, see [`DistributionRequest::histogram`].
+  pub fn metrics(query: &str) -> DistributionRequest {
+    DistributionRequest::histogram(Query::metrics("query1", query))
+  }
+  /// The distribution of APM stats.
+  pub fn apm_stats(apm_stats_query: ApmStatsQuery) -> DistributionRequest {
+    DistributionRequest {
+      apm_stats_query: Some(apm_stats_query),
+      ..Default::default()
+    }
+  }
+  pub fn palette(mut self, palette: &str) -> DistributionRequest {
+    self.style = Some(RequestStyle::default().palette(palette));
+    self
+  }
+}
+
+/// The distribution visualization shows data aggregated across one or several tags, such as hosts.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Distribution {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Available legend sizes for a widget. Should be one of `0`, `2`, `4`, `8`, `16`, or `auto`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub legend_size: Option<String>,
+  /// List of markers.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub markers: Option<Vec<Marker>>,
+  /// Array of one request object to display in the widget.
+  pub requests: Vec<DistributionRequest>,
+  /// Whether or not to display the legend on this widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_legend: Option<bool>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// X Axis controls for the distribution widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub xaxis: Option<Axis>,
+  /// Y Axis controls for the distribution widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub yaxis: Option<Axis>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Distribution {
+  pub fn title(mut self, title: &str) -> Distribution {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: DistributionRequest) -> Distribution {
+    self.requests.push(request);
+    self
+  }
+  /// Add a histogram of a metrics query, see [`DistributionRequest::metrics`].
+  pub fn metrics(self, query: &str) -> Distribution {
+    self.request(DistributionRequest::metrics(query))
+  }
+  pub fn marker(mut self, marker: Marker) -> Distribution {
+    self.markers.get_or_insert_with(Vec::new).push(marker);
+    self
+  }
+  pub fn xaxis(mut self, xaxis: Axis) -> Distribution {
+    self.xaxis = Some(xaxis);
+    self
+  }
+  pub fn yaxis(mut self, yaxis: Axis) -> Distribution {
+    self.yaxis = Some(yaxis);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> Distribution {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// Dimension of the scatterplot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScatterPlotDimension {
+  #[serde(rename = "x")]
+  X,
+  #[serde(rename = "y")]
+  Y,
+  #[serde(rename = "radius")]
+  Radius,
+  #[serde(rename = "color")]
+  Color,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ScatterPlotDimension {
+  fn default() -> ScatterPlotDimension {
+    ScatterPlotDimension::X
+  }
+}
+
+/// Formula to be used in a Scatterplot widget query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScatterPlotFormula {
+  /// Expression alias.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alias: Option<String>,
+  /// Dimension of the Scatterplot. Allowed enum values: `x`,`y`,`radius`,`color`
+  pub dimension: ScatterPlotDimension,
+  /// String expression built from queries, formulas, and functions.
+  pub formula: String,
+}
+
+/// Scatterplot request containing formulas and functions.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScatterPlotTableRequest {
+  /// List of Scatterplot formulas that operate on queries.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub formulas: Option<Vec<ScatterPlotFormula>>,
+  /// List of queries that can be returned directly or used in formulas.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub queries: Option<Vec<Query>>,
+  /// Timeseries or Scalar response. Allowed enum values: `timeseries`,`scalar`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub response_format: Option<ResponseFormat>,
+}
+impl ScatterPlotTableRequest {
+  /// One point per group, with the average of the `x` and `y` metrics queries, e.g. `avg:system.cpu.user{*} by {host}`.
+  pub fn metrics(x: &str, y: &str) -> ScatterPlotTableRequest {
+    let query = |name: &str, query: &str| MetricsQuery::new(name, query).aggregator(MetricAggregator::Average);
+    ScatterPlotTableRequest {
+      response_format: Some(ResponseFormat::Scalar),
+      ..Default::default()
+    }
+    .query(query("query1", x))
+    .query(query("query2", y))
+    .formula(ScatterPlotDimension::X, "query1")
+    .formula(ScatterPlotDimension::Y, "query2")
+  }
+  pub fn query<Q: Into<Query>>(mut self, query: Q) -> ScatterPlotTableRequest {
+    self.queries.get_or_insert_with(Vec::new).push(query.into());
+    self
+  }
+  pub fn formula(mut self, dimension: ScatterPlotDimension, formula: &str) -> ScatterPlotTableRequest {
+    self.formulas.get_or_insert_with(Vec::new).push(ScatterPlotFormula {
+      alias: None,
+      dimension,
+      formula: formula.into(),
+    });
+    self
+  }
+}
+
+/// Updated scatter plot.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScatterPlotRequest {
+  /// Aggregator used for the request. Allowed enum values: `avg`,`last`,`max`,`min`,`sum`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aggregator: Option<RequestAggregator>,
+  /// Query definition.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub q: Option<String>,
+}
+impl ScatterPlotRequest {
+  pub fn aggregator(mut self, aggregator: RequestAggregator) -> ScatterPlotRequest {
+    self.aggregator = Some(aggregator);
+    self
+  }
+  pub fn q(mut self, q: &str) -> ScatterPlotRequest {
+    self.q = Some(q.into());
+    self
+  }
+}
+
+/// Widget definition.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScatterPlotRequests {
+  /// Scatterplot request containing formulas and functions.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub table: Option<ScatterPlotTableRequest>,
+  /// Updated scatter plot, using `q` and `aggregator`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub x: Option<ScatterPlotRequest>,
+  /// Updated scatter plot, using `q` and `aggregator`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub y: Option<ScatterPlotRequest>,
+}
+
+/// The scatter plot visualization allows you to graph a chosen scope over two different metrics with their respective aggregation.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScatterPlot {
+  /// List of groups used for colors.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub color_by_groups: Option<Vec<String>>,
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Widget definition.
+  pub requests: ScatterPlotRequests,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Axis controls for the x-axis.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub xaxis: Option<Axis>,
+  /// Axis controls for the y-axis.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub yaxis: Option<Axis>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl ScatterPlot {
+  pub fn title(mut self, title: &str) -> ScatterPlot {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn table(mut self, table: ScatterPlotTableRequest) -> ScatterPlot {
+    self.requests.table = Some(table);
+    self
+  }
+  pub fn x(mut self, x: ScatterPlotRequest) -> ScatterPlot {
+    self.requests.x = Some(x);
+    self
+  }
+  pub fn y(mut self, y: ScatterPlotRequest) -> ScatterPlot {
+    self.requests.y = Some(y);
+    self
+  }
+  pub fn color_by_groups(mut self, color_by_groups: Vec<String>) -> ScatterPlot {
+    self.color_by_groups = Some(color_by_groups);
+    self
+  }
+  pub fn xaxis(mut self, xaxis: Axis) -> ScatterPlot {
+    self.xaxis = Some(xaxis);
+    self
+  }
+  pub fn yaxis(mut self, yaxis: Axis) -> ScatterPlot {
+    self.yaxis = Some(yaxis);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> ScatterPlot {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// Which type of node to use in the map.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NodeType {
+  #[serde(rename = "host")]
+  Host,
+  #[serde(rename = "container")]
+  Container,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for NodeType {
+  fn default() -> NodeType {
+    NodeType::Host
+  }
+}
+
+/// Updated host map.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HostmapRequest {
+  /// Query definition.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub q: Option<String>,
+}
+impl HostmapRequest {
+  pub fn q(mut self, q: &str) -> HostmapRequest {
+    self.q = Some(q.into());
+    self
+  }
+}
+
+/// List of definitions.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HostmapRequests {
+  /// Query used to fill the map, using `q`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fill: Option<HostmapRequest>,
+  /// Query used to size the map, using `q`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub size: Option<HostmapRequest>,
+}
+
+/// The style to apply to the widget.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HostmapStyle {
+  /// Max value to use to color the map.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fill_max: Option<String>,
+  /// Min value to use to color the map.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fill_min: Option<String>,
+  /// Color palette to apply to the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub palette: Option<String>,
+  /// Whether to flip the palette tones.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub palette_flip: Option<bool>,
+}
+impl HostmapStyle {
+  pub fn fill_max(mut self, fill_max: &str) -> HostmapStyle {
+    self.fill_max = Some(fill_max.into());
+    self
+  }
+  pub fn fill_min(mut self, fill_min: &str) -> HostmapStyle {
+    self.fill_min = Some(fill_min.into());
+    self
+  }
+  pub fn palette(mut self, palette: &str) -> HostmapStyle {
+    self.palette = Some(palette.into());
+    self
+  }
+  pub fn palette_flip(mut self, palette_flip: bool) -> HostmapStyle {
+    self.palette_flip = Some(palette_flip);
+    self
+  }
+}
+
+/// The host map widget graphs any metric across your hosts using the same visualization available from the main Host Map page.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hostmap {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// List of tag prefixes to group by.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub group: Option<Vec<String>>,
+  /// Whether to show the hosts that don't fit in a group.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub no_group_hosts: Option<bool>,
+  /// Whether to show the hosts with no metrics.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub no_metric_hosts: Option<bool>,
+  /// Which type of node to use in the map. Allowed enum values: `host`,`container`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub node_type: Option<NodeType>,
+  /// Notes on the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub notes: Option<String>,
+  /// List of definitions.
+  pub requests: HostmapRequests,
+  /// List of tags used to filter the map.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub scope: Option<Vec<String>>,
+  /// The style to apply to the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub style: Option<HostmapStyle>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Hostmap {
+  pub fn title(mut self, title: &str) -> Hostmap {
+    self.title = Some(title.into());
+    self
+  }
+  /// Fill the nodes with a metric query, e.g. `avg:system.cpu.user{*} by {host}`.
+  pub fn fill(mut self, q: &str) -> Hostmap {
+    self.requests.fill = Some(HostmapRequest::default().q(q));
+    self
+  }
+  /// Size the nodes with a metric query, e.g. `avg:system.mem.used{*} by {host}`.
+  pub fn size(mut self, q: &str) -> Hostmap {
+    self.requests.size = Some(HostmapRequest::default().q(q));
+    self
+  }
+  pub fn group(mut self, group: Vec<String>) -> Hostmap {
+    self.group = Some(group);
+    self
+  }
+  pub fn scope(mut self, scope: Vec<String>) -> Hostmap {
+    self.scope = Some(scope);
+    self
+  }
+  pub fn no_group_hosts(mut self, no_group_hosts: bool) -> Hostmap {
+    self.no_group_hosts = Some(no_group_hosts);
+    self
+  }
+  pub fn no_metric_hosts(mut self, no_metric_hosts: bool) -> Hostmap {
+    self.no_metric_hosts = Some(no_metric_hosts);
+    self
+  }
+  pub fn node_type(mut self, node_type: NodeType) -> Hostmap {
+    self.node_type = Some(node_type);
+    self
+  }
+  pub fn style(mut self, style: HostmapStyle) -> Hostmap {
+    self.style = Some(style);
+    self
+  }
+}
+
+/// The style to apply to the widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeomapStyle {
+  /// The color palette to apply to the widget.
+  pub palette: String,
+  /// Whether to flip the palette tones.
+  pub palette_flip: bool,
+}
+impl Default for GeomapStyle {
+  fn default() -> GeomapStyle {
+    GeomapStyle {
+      palette: "hostmap_blues".into(),
+      palette_flip: false,
+    }
+  }
+}
+
+/// The view of the world that the map should render.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeomapView {
+  /// The 2-letter ISO code of a country to focus the map on. Or `WORLD`.
+  pub focus: String,
+}
+impl Default for GeomapView {
+  fn default() -> GeomapView {
+    GeomapView {
+      focus: "WORLD".into(),
+    }
+  }
+}
+
+/// This visualization displays a world map with countries colored by a metric, grouped by a country ISO code tag.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Geomap {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Array of one request object to display in the widget. The request must contain a `group-by` tag whose value is a country ISO code.
+  pub requests: Vec<Request>,
+  /// The style to apply to the widget.
+  pub style: GeomapStyle,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// The view of the world that the map should render.
+  pub view: GeomapView,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Geomap {
+  pub fn title(mut self, title: &str) -> Geomap {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn request(mut self, request: Request) -> Geomap {
+    self.requests.push(request);
+    self
+  }
+  /// Color countries with a metrics query grouped by country ISO code, e.g. `sum:app.visits{*} by {country_iso_code}`.
+  pub fn metrics(self, query: &str) -> Geomap {
+    self.request(
+      Request::default()
+        .query(Query::metrics("query1", query))
+        .formula("query1")
+        .response_format(ResponseFormat::Scalar),
+    )
+  }
+  pub fn palette(mut self, palette: &str, palette_flip: bool) -> Geomap {
+    self.style = GeomapStyle {
+      palette: palette.into(),
+      palette_flip,
+    };
+    self
+  }
+  /// Focus on a country by 2-letter ISO code, or `WORLD`.
+  pub fn focus(mut self, focus: &str) -> Geomap {
+    self.view.focus = focus.into();
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> Geomap {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// This widget displays a map of a service to all of the services that call it, and all of the services that it calls.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServiceMap {
+  /// List of custom links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_links: Option<Vec<CustomLink>>,
+  /// Your environment and primary tag (or `*` if enabled for your account).
+  pub filters: Vec<String>,
+  /// The ID of the service you want to map.
+  pub service: String,
+  /// The title of your widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl ServiceMap {
+  pub fn title(mut self, title: &str) -> ServiceMap {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn service(mut self, service: &str) -> ServiceMap {
+    self.service = service.into();
+    self
+  }
+  /// Filter on an environment, adding an `env:` filter.
+  pub fn env(self, env: &str) -> ServiceMap {
+    self.filter(&format!("env:{}", env))
+  }
+  /// Filter on a tag, e.g. the primary tag `datacenter:us1`.
+  pub fn filter(mut self, filter: &str) -> ServiceMap {
+    self.filters.push(filter.into());
+    self
+  }
+}
+
+/// Time windows of an SLO widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TimeWindow {
+  #[serde(rename = "7d")]
+  SevenDays,
+  #[serde(rename = "30d")]
+  ThirtyDays,
+  #[serde(rename = "90d")]
+  NinetyDays,
+  #[serde(rename = "week_to_date")]
+  WeekToDate,
+  #[serde(rename = "previous_week")]
+  PreviousWeek,
+  #[serde(rename = "month_to_date")]
+  MonthToDate,
+  #[serde(rename = "previous_month")]
+  PreviousMonth,
+  #[serde(rename = "global_time")]
+  GlobalTime,
+}
+
+/// Define how you want the SLO to be displayed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SloViewMode {
+  #[serde(rename = "overall")]
+  Overall,
+  #[serde(rename = "component")]
+  Component,
+  #[serde(rename = "both")]
+  Both,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for SloViewMode {
+  fn default() -> SloViewMode {
+    SloViewMode::Overall
+  }
+}
+
+/// Use the SLO and uptime widget to track your SLOs (Service Level Objectives) and uptime on screenboards and timeboards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServiceLevelObjective {
+  /// Additional filters applied to the SLO query.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub additional_query_filters: Option<String>,
+  /// Defined global time target.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub global_time_target: Option<String>,
+  /// Defined error budget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_error_budget: Option<bool>,
+  /// ID of the SLO displayed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub slo_id: Option<String>,
+  /// Times being monitored.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time_windows: Option<Vec<TimeWindow>>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Define how you want the SLO to be displayed. Allowed enum values: `overall`,`component`,`both`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub view_mode: Option<SloViewMode>,
+  /// Type of view displayed by the widget.
+  pub view_type: String,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Default for ServiceLevelObjective {
+  fn default() -> ServiceLevelObjective {
+    ServiceLevelObjective {
+      additional_query_filters: None,
+      global_time_target: None,
+      show_error_budget: None,
+      slo_id: None,
+      time_windows: None,
+      title: None,
+      title_align: None,
+      title_size: None,
+      view_mode: None,
+      view_type: "detail".into(),
+      extra: Map::new(),
+    }
+  }
+}
+impl ServiceLevelObjective {
+  pub fn title(mut self, title: &str) -> ServiceLevelObjective {
+    self.title = Some(title.into());
+    self
+  }
+  pub fn slo_id(mut self, slo_id: &str) -> ServiceLevelObjective {
+    self.slo_id = Some(slo_id.into());
+    self
+  }
+  pub fn time_windows(mut self, time_windows: Vec<TimeWindow>) -> ServiceLevelObjective {
+    self.time_windows = Some(time_windows);
+    self
+  }
+  pub fn view_mode(mut self, view_mode: SloViewMode) -> ServiceLevelObjective {
+    self.view_mode = Some(view_mode);
+    self
+  }
+  /// Target for the `global_time` window, e.g. `99.9`.
+  pub fn global_time_target(mut self, global_time_target: &str) -> ServiceLevelObjective {
+    self.global_time_target = Some(global_time_target.into());
+    self
+  }
+  pub fn show_error_budget(mut self, show_error_budget: bool) -> ServiceLevelObjective {
+    self.show_error_budget = Some(show_error_budget);
+    self
+  }
+  pub fn additional_query_filters(mut self, additional_query_filters: &str) -> ServiceLevelObjective {
+    self.additional_query_filters = Some(additional_query_filters.into());
+    self
+  }
+}
+
+/// Which summary type should be used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SummaryType {
+  #[serde(rename = "monitors")]
+  Monitors,
+  #[serde(rename = "groups")]
+  Groups,
+  #[serde(rename = "combined")]
+  Combined,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for SummaryType {
+  fn default() -> SummaryType {
+    SummaryType::Monitors
+  }
+}
+
+/// What to display on the widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MonitorSummaryDisplayFormat {
+  #[serde(rename = "counts")]
+  Counts,
+  #[serde(rename = "countsAndList")]
+  CountsAndList,
+  #[serde(rename = "list")]
+  List,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for MonitorSummaryDisplayFormat {
+  fn default() -> MonitorSummaryDisplayFormat {
+    MonitorSummaryDisplayFormat::CountsAndList
+  }
+}
+
+/// Which color to use on the widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ColorPreference {
+  #[serde(rename = "background")]
+  Background,
+  #[serde(rename = "text")]
+  Text,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ColorPreference {
+  fn default() -> ColorPreference {
+    ColorPreference::Background
+  }
+}
+
+/// The monitor summary widget displays a summary view of all your Datadog monitors, or a subset based on a query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorSummary {
+  /// Which color to use on the widget. Allowed enum values: `background`,`text`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub color_preference: Option<ColorPreference>,
+  /// What to display on the widget. Allowed enum values: `counts`,`countsAndList`,`list`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_format: Option<MonitorSummaryDisplayFormat>,
+  /// Whether to show counts of 0 or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hide_zero_counts: Option<bool>,
+  /// Query to filter the monitors with.
+  pub query: String,
+  /// Whether to show the time that has elapsed since the monitor/group triggered.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_last_triggered: Option<bool>,
+  /// Whether to show the priorities column.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_priority: Option<bool>,
+  /// Widget sorting methods, e.g. `status,asc` or `triggered,desc`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort: Option<String>,
+  /// Which summary type should be used. Allowed enum values: `monitors`,`groups`,`combined`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub summary_type: Option<SummaryType>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl MonitorSummary {
+  pub fn title(mut self, title: &str) -> MonitorSummary {
+    self.title = Some(title.into());
+    self
+  }
+  /// Monitor search query, e.g. `tag:"team:sre" status:alert`.
+  pub fn query(mut self, query: &str) -> MonitorSummary {
+    self.query = query.into();
+    self
+  }
+  /// Only summarize the given monitors.
+  pub fn monitor_ids(self, monitor_ids: &[u64]) -> MonitorSummary {
+    let ids: Vec<String> = monitor_ids.iter().map(|monitor_id| format!("id:{}", monitor_id)).collect();
+    self.query(&ids.join(" OR "))
+  }
+  pub fn sort(mut self, sort: &str) -> MonitorSummary {
+    self.sort = Some(sort.into());
+    self
+  }
+  pub fn display_format(mut self, display_format: MonitorSummaryDisplayFormat) -> MonitorSummary {
+    self.display_format = Some(display_format);
+    self
+  }
+  pub fn summary_type(mut self, summary_type: SummaryType) -> MonitorSummary {
+    self.summary_type = Some(summary_type);
+    self
+  }
+  pub fn color_preference(mut self, color_preference: ColorPreference) -> MonitorSummary {
+    self.color_preference = Some(color_preference);
+    self
+  }
+  pub fn hide_zero_counts(mut self, hide_zero_counts: bool) -> MonitorSummary {
+    self.hide_zero_counts = Some(hide_zero_counts);
+    self
+  }
+  pub fn show_last_triggered(mut self, show_last_triggered: bool) -> MonitorSummary {
+    self.show_last_triggered = Some(show_last_triggered);
+    self
+  }
+  pub fn show_priority(mut self, show_priority: bool) -> MonitorSummary {
+    self.show_priority = Some(show_priority);
+    self
+  }
+}
+
+/// Whether to display the Alert Graph as a timeseries or a top list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VizType {
+  #[serde(rename = "timeseries")]
+  Timeseries,
+  #[serde(rename = "toplist")]
+  Toplist,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for VizType {
+  fn default() -> VizType {
+    VizType::Timeseries
+  }
+}
+
+/// Alert graphs are timeseries graphs showing the current status of any monitor defined on your system.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertGraph {
+  /// ID of the alert to use in the widget.
+  pub alert_id: String,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// The title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Whether to display the Alert Graph as a timeseries or a top list. Allowed enum values: `timeseries`,`toplist`
+  pub viz_type: VizType,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl AlertGraph {
+  pub fn title(mut self, title: &str) -> AlertGraph {
+    self.title = Some(title.into());
+    self
+  }
+  /// The ID of the monitor to graph.
+  pub fn alert_id(mut self, monitor_id: u64) -> AlertGraph {
+    self.alert_id = monitor_id.to_string();
+    self
+  }
+  pub fn viz_type(mut self, viz_type: VizType) -> AlertGraph {
+    self.viz_type = viz_type;
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> AlertGraph {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+impl From<&GetMonitorResponse> for AlertGraph {
+  /// Graph a monitor, titled with its name.
+  fn from(monitor: &GetMonitorResponse) -> AlertGraph {
+    AlertGraph::default().alert_id(monitor.id).title(&monitor.name)
+  }
+}
+
+/// Alert values are query values showing the current value of the metric in any monitor defined on your system.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertValue {
+  /// ID of the alert to use in the widget.
+  pub alert_id: String,
+  /// Number of decimal to show. If not defined, will use the raw value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub precision: Option<i64>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub text_align: Option<TextAlign>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of value in the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Unit to display with the value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl AlertValue {
+  pub fn title(mut self, title: &str) -> AlertValue {
+    self.title = Some(title.into());
+    self
+  }
+  /// The ID of the monitor to show the value of.
+  pub fn alert_id(mut self, monitor_id: u64) -> AlertValue {
+    self.alert_id = monitor_id.to_string();
+    self
+  }
+  pub fn precision(mut self, precision: i64) -> AlertValue {
+    self.precision = Some(precision);
+    self
+  }
+  pub fn unit(mut self, unit: &str) -> AlertValue {
+    self.unit = Some(unit.into());
+    self
+  }
+  pub fn text_align(mut self, text_align: TextAlign) -> AlertValue {
+    self.text_align = Some(text_align);
+    self
+  }
+}
+impl From<&GetMonitorResponse> for AlertValue {
+  /// Show the value of a monitor, titled with its name.
+  fn from(monitor: &GetMonitorResponse) -> AlertValue {
+    AlertValue::default().alert_id(monitor.id).title(&monitor.name)
+  }
+}
+
+/// The kind of grouping to use.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CheckGrouping {
+  #[serde(rename = "check")]
+  Check,
+  #[serde(rename = "cluster")]
+  Cluster,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for CheckGrouping {
+  fn default() -> CheckGrouping {
+    CheckGrouping::Check
+  }
+}
+
+/// Check status shows the current status or number of results for any check performed.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckStatus {
+  /// Name of the check to use in the widget.
+  pub check: String,
+  /// Group reporting a single check.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub group: Option<String>,
+  /// List of tag prefixes to group by in the case of a cluster check.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub group_by: Option<Vec<String>>,
+  /// The kind of grouping to use. Allowed enum values: `check`,`cluster`
+  pub grouping: CheckGrouping,
+  /// List of tags used to filter the groups reporting a cluster check.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<Vec<String>>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl CheckStatus {
+  pub fn title(mut self, title: &str) -> CheckStatus {
+    self.title = Some(title.into());
+    self
+  }
+  /// Name of the check, e.g. `datadog.agent.up`.
+  pub fn check(mut self, check: &str) -> CheckStatus {
+    self.check = check.into();
+    self
+  }
+  /// Show a single group, e.g. `host:web-1`, of the check.
+  pub fn group(mut self, group: &str) -> CheckStatus {
+    self.grouping = CheckGrouping::Check;
+    self.group = Some(group.into());
+    self
+  }
+  /// Show the number of groups by status, grouped by the tag prefixes and filtered on the tags.
+  pub fn cluster(mut self, group_by: Vec<String>, tags: Vec<String>) -> CheckStatus {
+    self.grouping = CheckGrouping::Cluster;
+    self.group_by = Some(group_by);
+    self.tags = Some(tags);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> CheckStatus {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// Amount of log lines to display.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MessageDisplay {
+  #[serde(rename = "inline")]
+  Inline,
+  #[serde(rename = "expanded-md")]
+  ExpandedMedium,
+  #[serde(rename = "expanded-lg")]
+  ExpandedLarge,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for MessageDisplay {
+  fn default() -> MessageDisplay {
+    MessageDisplay::Inline
+  }
+}
+
+/// Which column and order to sort by.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FieldSort {
+  /// Facet path for the column.
+  pub column: String,
+  /// Widget sorting methods. Allowed enum values: `asc`,`desc`
+  pub order: SortOrder,
+}
+
+/// The Log Stream displays a log flow matching the defined query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogStream {
+  /// Which columns to display on the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub columns: Option<Vec<String>>,
+  /// An array of index names to query in the stream. Use `[]` to query all indexes at once.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub indexes: Option<Vec<String>>,
+  /// Amount of log lines to display. Allowed enum values: `inline`,`expanded-md`,`expanded-lg`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message_display: Option<MessageDisplay>,
+  /// Query to filter the log stream with.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub query: Option<String>,
+  /// Whether to show the date column or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_date_column: Option<bool>,
+  /// Whether to show the message column or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_message_column: Option<bool>,
+  /// Which column and order to sort by.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort: Option<FieldSort>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl LogStream {
+  pub fn title(mut self, title: &str) -> LogStream {
+    self.title = Some(title.into());
+    self
+  }
+  /// Log search query, e.g. `service:web status:error`.
+  pub fn query(mut self, query: &str) -> LogStream {
+    self.query = Some(query.into());
+    self
+  }
+  pub fn indexes(mut self, indexes: Vec<String>) -> LogStream {
+    self.indexes = Some(indexes);
+    self
+  }
+  pub fn columns(mut self, columns: Vec<String>) -> LogStream {
+    self.columns = Some(columns);
+    self
+  }
+  pub fn sort(mut self, column: &str, order: SortOrder) -> LogStream {
+    self.sort = Some(FieldSort {
+      column: column.into(),
+      order,
+    });
+    self
+  }
+  pub fn message_display(mut self, message_display: MessageDisplay) -> LogStream {
+    self.message_display = Some(message_display);
+    self
+  }
+  pub fn show_date_column(mut self, show_date_column: bool) -> LogStream {
+    self.show_date_column = Some(show_date_column);
+    self
+  }
+  pub fn show_message_column(mut self, show_message_column: bool) -> LogStream {
+    self.show_message_column = Some(show_message_column);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> LogStream {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// Size to use to display an event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EventSize {
+  #[serde(rename = "s")]
+  Small,
+  #[serde(rename = "l")]
+  Large,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for EventSize {
+  fn default() -> EventSize {
+    EventSize::Small
+  }
+}
+
+/// The event stream is a widget version of the stream of events on the Event Stream view.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventStream {
+  /// Size to use to display an event. Allowed enum values: `s`,`l`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub event_size: Option<EventSize>,
+  /// Query to filter the event stream with.
+  pub query: String,
+  /// The execution method for multi-value filters. Can be either `and` or `or`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags_execution: Option<String>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl EventStream {
+  pub fn title(mut self, title: &str) -> EventStream {
+    self.title = Some(title.into());
+    self
+  }
+  /// Event search query, e.g. `sources:deploy`.
+  pub fn query(mut self, query: &str) -> EventStream {
+    self.query = query.into();
+    self
+  }
+  pub fn tags_execution(mut self, tags_execution: &str) -> EventStream {
+    self.tags_execution = Some(tags_execution.into());
+    self
+  }
+  pub fn event_size(mut self, event_size: EventSize) -> EventStream {
+    self.event_size = Some(event_size);
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> EventStream {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// The event timeline is a widget version of the timeline that appears at the top of the Event Stream view.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventTimeline {
+  /// Query to filter the event timeline with.
+  pub query: String,
+  /// The execution method for multi-value filters. Can be either `and` or `or`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags_execution: Option<String>,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl EventTimeline {
+  pub fn title(mut self, title: &str) -> EventTimeline {
+    self.title = Some(title.into());
+    self
+  }
+  /// Event search query, e.g. `sources:deploy`.
+  pub fn query(mut self, query: &str) -> EventTimeline {
+    self.query = query.into();
+    self
+  }
+  pub fn tags_execution(mut self, tags_execution: &str) -> EventTimeline {
+    self.tags_execution = Some(tags_execution.into());
+    self
+  }
+  /// Show a fixed timeframe, e.g. `4h`, instead of the dashboard's.
+  pub fn live_span(mut self, live_span: &str) -> EventTimeline {
+    self.time = Some(Time::default().live_span(live_span));
+    self
+  }
+}
+
+/// The iframe widget allows you to embed a portion of any other web page on your dashboard.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Iframe {
+  /// URL of the iframe.
+  pub url: String,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Iframe {
+  pub fn url(mut self, url: &str) -> Iframe {
+    self.url = url.into();
+    self
+  }
+}
+
+/// How to size the image on the widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ImageSizing {
+  #[serde(rename = "fill")]
+  Fill,
+  #[serde(rename = "contain")]
+  Contain,
+  #[serde(rename = "cover")]
+  Cover,
+  #[serde(rename = "none")]
+  None,
+  #[serde(rename = "scale-down")]
+  ScaleDown,
+  #[serde(rename = "zoom")]
+  Zoom,
+  #[serde(rename = "fit")]
+  Fit,
+  #[serde(rename = "center")]
+  Center,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ImageSizing {
+  fn default() -> ImageSizing {
+    ImageSizing::Contain
+  }
+}
+
+/// Size of the margins around the image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Margin {
+  #[serde(rename = "sm")]
+  Small,
+  #[serde(rename = "md")]
+  Medium,
+  #[serde(rename = "lg")]
+  Large,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for Margin {
+  fn default() -> Margin {
+    Margin::Medium
+  }
+}
+
+/// The image widget allows you to embed an image on your dashboard.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Image {
+  /// Whether to display a background or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub has_background: Option<bool>,
+  /// Whether to display a border or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub has_border: Option<bool>,
+  /// Horizontal alignment. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub horizontal_align: Option<TextAlign>,
+  /// Size of the margins around the image. Allowed enum values: `sm`,`md`,`lg`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub margin: Option<Margin>,
+  /// How to size the image on the widget. Allowed enum values: `fill`,`contain`,`cover`,`none`,`scale-down`,`zoom`,`fit`,`center`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sizing: Option<ImageSizing>,
+  /// URL of the image.
+  pub url: String,
+  /// URL of the image in dark mode.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url_dark_theme: Option<String>,
+  /// Vertical alignment. Allowed enum values: `center`,`top`,`bottom`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub vertical_align: Option<VerticalAlign>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl Image {
+  pub fn url(mut self, url: &str) -> Image {
+    self.url = url.into();
+    self
+  }
+  pub fn url_dark_theme(mut self, url_dark_theme: &str) -> Image {
+    self.url_dark_theme = Some(url_dark_theme.into());
+    self
+  }
+  pub fn sizing(mut self, sizing: ImageSizing) -> Image {
+    self.sizing = Some(sizing);
+    self
+  }
+  pub fn margin(mut self, margin: Margin) -> Image {
+    self.margin = Some(margin);
+    self
+  }
+  pub fn has_background(mut self, has_background: bool) -> Image {
+    self.has_background = Some(has_background);
+    self
+  }
+  pub fn has_border(mut self, has_border: bool) -> Image {
+    self.has_border = Some(has_border);
+    self
+  }
+}
+
+/// Free text is a widget that allows you to add headings to your screenboard.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FreeText {
+  /// Color of the text.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub color: Option<String>,
+  /// Size of the text.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub font_size: Option<String>,
+  /// Text to display.
+  pub text: String,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub text_align: Option<TextAlign>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl FreeText {
+  pub fn text(mut self, text: &str) -> FreeText {
+    self.text = text.into();
+    self
+  }
+  pub fn color(mut self, color: &str) -> FreeText {
+    self.color = Some(color.into());
+    self
+  }
+  pub fn font_size(mut self, font_size: &str) -> FreeText {
+    self.font_size = Some(font_size.into());
+    self
+  }
+  pub fn text_align(mut self, text_align: TextAlign) -> FreeText {
+    self.text_align = Some(text_align);
+    self
+  }
+}
+
+/// Number of columns to display.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServiceSummaryDisplayFormat {
+  #[serde(rename = "one_column")]
+  OneColumn,
+  #[serde(rename = "two_column")]
+  TwoColumn,
+  #[serde(rename = "three_column")]
+  ThreeColumn,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ServiceSummaryDisplayFormat {
+  fn default() -> ServiceSummaryDisplayFormat {
+    ServiceSummaryDisplayFormat::TwoColumn
+  }
+}
+
+/// Size of the widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SizeFormat {
+  #[serde(rename = "small")]
+  Small,
+  #[serde(rename = "medium")]
+  Medium,
+  #[serde(rename = "large")]
+  Large,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for SizeFormat {
+  fn default() -> SizeFormat {
+    SizeFormat::Medium
+  }
+}
+
+/// The service summary displays the graphs of a chosen service in your screenboard.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServiceSummary {
+  /// Number of columns to display. Allowed enum values: `one_column`,`two_column`,`three_column`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_format: Option<ServiceSummaryDisplayFormat>,
+  /// APM environment.
+  pub env: String,
+  /// APM service.
+  pub service: String,
+  /// Whether to show the latency breakdown or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_breakdown: Option<bool>,
+  /// Whether to show the latency distribution or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_distribution: Option<bool>,
+  /// Whether to show the error metrics or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_errors: Option<bool>,
+  /// Whether to show the hits metrics or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_hits: Option<bool>,
+  /// Whether to show the latency metrics or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_latency: Option<bool>,
+  /// Whether to show the resource list or not.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_resource_list: Option<bool>,
+  /// Size of the widget. Allowed enum values: `small`,`medium`,`large`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub size_format: Option<SizeFormat>,
+  /// APM span name.
+  pub span_name: String,
+  /// Time setting for the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<Time>,
+  /// Title of the widget.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// How to align the text on the widget. Allowed enum values: `center`,`left`,`right`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_align: Option<TextAlign>,
+  /// Size of the title.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_size: Option<String>,
+  /// Fields this crate doesn't model, kept so they serialize back unchanged.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
+impl ServiceSummary {
+  pub fn title(mut self, title: &str) -> ServiceSummary {
+    self.title = Some(title.into());
+    self
+  }
+  /// The APM service, environment and span name, e.g. `web`, `prod` and `http.request`.
+  pub fn service(mut self, service: &str, env: &str, span_name: &str) -> ServiceSummary {
+    self.service = service.into();
+    self.env = env.into();
+    self.span_name = span_name.into();
+    self
+  }
+  pub fn display_format(mut self, display_format: ServiceSummaryDisplayFormat) -> ServiceSummary {
+    self.display_format = Some(display_format);
+    self
+  }
+  pub fn size_format(mut self, size_format: SizeFormat) -> ServiceSummary {
+    self.size_format = Some(size_format);
+    self
+  }
+}
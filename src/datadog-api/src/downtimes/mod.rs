@@ -0,0 +1,647 @@
+//! Downtimes give you greater control over monitor notifications by allowing you to globally exclude scopes from alerting.
+//! Downtime settings, which can be scheduled with start and end times, prevent all alerting related to specified Datadog tags.
+use async_gen::{self, AsyncIter};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::client::*;
+use crate::monitors::MuteMonitorRequest;
+
+/// The monitors the downtime applies to, by ID or by tags.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DowntimeMonitorIdentifier {
+  /// A single monitor.
+  Id {
+    /// ID of the monitor to prevent notifications.
+    monitor_id: u64,
+  },
+  /// Every monitor with all the given tags.
+  Tags {
+    /// A list of monitor tags, e.g. `service:postgres`. Use `*` for all monitors.
+    monitor_tags: Vec<String>,
+  },
+}
+impl Default for DowntimeMonitorIdentifier {
+  fn default() -> DowntimeMonitorIdentifier {
+    DowntimeMonitorIdentifier::Tags {
+      monitor_tags: vec!["*".into()],
+    }
+  }
+}
+
+/// A recurrence of a downtime, following the iCalendar RRULE format.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeRecurrence {
+  /// The length of each downtime, e.g. `1d`, `2h` or `30m`.
+  pub duration: String,
+  /// The [RRULE](https://icalendar.org/rrule-tool.html) of the recurrence, e.g. `FREQ=WEEKLY;BYDAY=MO,TU`. `DTSTART`, `DTEND` and `DURATION` are not supported.
+  pub rrule: String,
+  /// ISO-8601 datetime, without timezone, of the first recurrence, e.g. `2020-01-02T03:04`. Defaults to now.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub start: Option<String>,
+}
+impl DowntimeRecurrence {
+  pub fn duration(mut self, duration: Duration) -> DowntimeRecurrence {
+    self.duration = format_duration(duration);
+    self
+  }
+  pub fn rrule(mut self, rrule: &str) -> DowntimeRecurrence {
+    self.rrule = rrule.into();
+    self
+  }
+  pub fn start(mut self, start: NaiveDateTime) -> DowntimeRecurrence {
+    self.start = Some(start.format("%Y-%m-%dT%H:%M").to_string());
+    self
+  }
+}
+
+/// The currently active downtime of a recurring schedule.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeCurrentDowntime {
+  pub end: Option<String>,
+  pub start: Option<String>,
+}
+
+/// A schedule of repeating downtimes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DowntimeRecurringSchedule {
+  /// The currently active downtime. (Only returned in responses)
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub current_downtime: Option<DowntimeCurrentDowntime>,
+  /// A list of downtime recurrences.
+  pub recurrences: Vec<DowntimeRecurrence>,
+  /// The timezone the recurrences are evaluated in, e.g. `America/New_York`. Defaults to `UTC`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub timezone: Option<String>,
+}
+
+/// A single downtime between a start and an optional end.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeOneTimeSchedule {
+  /// ISO-8601 datetime the downtime ends. The downtime lasts until canceled when not set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end: Option<String>,
+  /// ISO-8601 datetime the downtime starts. Defaults to now.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub start: Option<String>,
+}
+
+/// When the downtime is active.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DowntimeSchedule {
+  Recurring(DowntimeRecurringSchedule),
+  OneTime(DowntimeOneTimeSchedule),
+}
+impl Default for DowntimeSchedule {
+  fn default() -> DowntimeSchedule {
+    DowntimeSchedule::OneTime(DowntimeOneTimeSchedule::default())
+  }
+}
+impl DowntimeSchedule {
+  /// A downtime from `start` until `end`, or until canceled.
+  pub fn one_time(start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> DowntimeSchedule {
+    DowntimeSchedule::OneTime(DowntimeOneTimeSchedule {
+      start: Some(start.to_rfc3339()),
+      end: end.map(|end| end.to_rfc3339()),
+    })
+  }
+
+  /// A downtime from now for `duration`.
+  pub fn from_now(duration: Duration) -> DowntimeSchedule {
+    let now = Utc::now();
+    DowntimeSchedule::one_time(now, Some(now + duration))
+  }
+
+  /// Repeating downtimes evaluated in `timezone`.
+  pub fn recurring(recurrences: Vec<DowntimeRecurrence>, timezone: &str) -> DowntimeSchedule {
+    DowntimeSchedule::Recurring(DowntimeRecurringSchedule {
+      current_downtime: None,
+      recurrences,
+      timezone: Some(timezone.into()),
+    })
+  }
+}
+
+/// Format a duration as a downtime recurrence duration, in the largest unit that divides it, e.g. `90m` or `2h`.
+pub fn format_duration(duration: Duration) -> String {
+  let minutes = duration.num_minutes().max(1);
+  match minutes {
+    m if m % (60 * 24 * 7) == 0 => format!("{}w", m / (60 * 24 * 7)),
+    m if m % (60 * 24) == 0 => format!("{}d", m / (60 * 24)),
+    m if m % 60 == 0 => format!("{}h", m / 60),
+    m => format!("{}m", m),
+  }
+}
+
+/// States that trigger a monitor notification when the downtime ends, if the monitor is still in that state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotifyEndState {
+  #[serde(rename = "alert")]
+  Alert,
+  #[serde(rename = "no data")]
+  NoData,
+  #[serde(rename = "warn")]
+  Warn,
+}
+
+/// Ways the downtime can end that trigger a monitor notification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotifyEndType {
+  #[serde(rename = "canceled")]
+  Canceled,
+  #[serde(rename = "expired")]
+  Expired,
+}
+
+/// The current status of a downtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DowntimeStatus {
+  #[serde(rename = "active")]
+  Active,
+  #[serde(rename = "canceled")]
+  Canceled,
+  #[serde(rename = "ended")]
+  Ended,
+  #[serde(rename = "scheduled")]
+  Scheduled,
+}
+
+/// Downtime definition, used to create and update downtimes. Fields left unset are not changed by an update.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeAttributes {
+  /// The timezone in which to display the downtime's start and end times in Datadog applications, e.g. `America/New_York`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_timezone: Option<String>,
+  /// A message to include with notifications for this downtime.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  /// The monitors the downtime applies to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub monitor_identifier: Option<DowntimeMonitorIdentifier>,
+  /// If the first recovery notification during a downtime should be muted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub mute_first_recovery_notification: Option<bool>,
+  /// States that re-trigger a monitor notification when the downtime ends. Defaults to `alert`, `no data` and `warn`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub notify_end_states: Option<Vec<NotifyEndState>>,
+  /// Actions that trigger a monitor notification if the monitor is in a notify end state. Defaults to `expired`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub notify_end_types: Option<Vec<NotifyEndType>>,
+  /// When the downtime is active.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub schedule: Option<DowntimeSchedule>,
+  /// The scope the downtime applies to, following the [downtime scope syntax](https://docs.datadoghq.com/monitors/downtimes/?tab=api#scope), e.g. `env:(staging OR prod) AND datacenter:us-east-1`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub scope: Option<String>,
+}
+
+/// Downtime data sent to create or update a downtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeRequestData {
+  /// ID of the downtime, only sent in updates.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// Downtime resource type. Allowed enum values: `downtime`
+  #[serde(rename = "type")]
+  pub _type: String,
+  /// Downtime definition.
+  pub attributes: DowntimeAttributes,
+}
+impl Default for DowntimeRequestData {
+  fn default() -> DowntimeRequestData {
+    DowntimeRequestData {
+      id: None,
+      _type: "downtime".into(),
+      attributes: DowntimeAttributes::default(),
+    }
+  }
+}
+
+/// [Schedule a downtime](https://docs.datadoghq.com/api/latest/downtimes/#schedule-a-downtime)
+///
+/// Schedule a downtime, by default for all monitors in the scope.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateDowntimeRequest {
+  pub data: DowntimeRequestData,
+}
+impl CreateDowntimeRequest {
+  pub fn display_timezone(mut self, display_timezone: &str) -> CreateDowntimeRequest {
+    self.data.attributes.display_timezone = Some(display_timezone.into());
+    self
+  }
+  pub fn message(mut self, message: &str) -> CreateDowntimeRequest {
+    self.data.attributes.message = Some(message.into());
+    self
+  }
+  pub fn monitor_id(mut self, monitor_id: u64) -> CreateDowntimeRequest {
+    self.data.attributes.monitor_identifier = Some(DowntimeMonitorIdentifier::Id { monitor_id });
+    self
+  }
+  pub fn monitor_tags(mut self, monitor_tags: Vec<String>) -> CreateDowntimeRequest {
+    self.data.attributes.monitor_identifier = Some(DowntimeMonitorIdentifier::Tags { monitor_tags });
+    self
+  }
+  pub fn mute_first_recovery_notification(mut self, mute_first_recovery_notification: bool) -> CreateDowntimeRequest {
+    self.data.attributes.mute_first_recovery_notification = Some(mute_first_recovery_notification);
+    self
+  }
+  pub fn notify_end_states(mut self, notify_end_states: Vec<NotifyEndState>) -> CreateDowntimeRequest {
+    self.data.attributes.notify_end_states = Some(notify_end_states);
+    self
+  }
+  pub fn notify_end_types(mut self, notify_end_types: Vec<NotifyEndType>) -> CreateDowntimeRequest {
+    self.data.attributes.notify_end_types = Some(notify_end_types);
+    self
+  }
+  pub fn schedule(mut self, schedule: DowntimeSchedule) -> CreateDowntimeRequest {
+    self.data.attributes.schedule = Some(schedule);
+    self
+  }
+  pub fn scope(mut self, scope: &str) -> CreateDowntimeRequest {
+    self.data.attributes.scope = Some(scope.into());
+    self
+  }
+  /// Mute from now for `duration`, e.g. `Duration::minutes(30)` around a release.
+  pub fn mute_for(self, duration: Duration) -> CreateDowntimeRequest {
+    self.schedule(DowntimeSchedule::from_now(duration))
+  }
+}
+
+impl CreateDowntimeRequest {
+  pub async fn send(&self, client: &Client) -> DatadogResult<DowntimeResponse> {
+    let path_and_query = "/api/v2/downtime";
+
+    client
+      .post::<CreateDowntimeRequest, DowntimeResponse>(path_and_query, self)
+      .await
+  }
+}
+
+impl From<MuteMonitorRequest> for CreateDowntimeRequest {
+  /// The downtime equivalent of muting a monitor, as monitor muting is deprecated.
+  fn from(mute: MuteMonitorRequest) -> CreateDowntimeRequest {
+    let end = mute
+      .end
+      .and_then(|end| DateTime::<Utc>::from_timestamp(end, 0));
+    CreateDowntimeRequest::default()
+      .monitor_id(mute.monitor_id)
+      .scope(&mute.scope.unwrap_or_else(|| "*".into()))
+      .schedule(DowntimeSchedule::one_time(Utc::now(), end))
+  }
+}
+
+/// [Update a downtime](https://docs.datadoghq.com/api/latest/downtimes/#update-a-downtime)
+///
+/// Update a downtime by its ID. Only the fields that are set are changed.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateDowntimeRequest {
+  // Path
+  /// ID of the downtime to update.
+  #[serde(skip_serializing)]
+  pub downtime_id: String,
+  // Body
+  pub data: DowntimeRequestData,
+}
+impl UpdateDowntimeRequest {
+  pub fn downtime_id(mut self, downtime_id: &str) -> UpdateDowntimeRequest {
+    self.downtime_id = downtime_id.into();
+    self.data.id = Some(downtime_id.into());
+    self
+  }
+  pub fn display_timezone(mut self, display_timezone: &str) -> UpdateDowntimeRequest {
+    self.data.attributes.display_timezone = Some(display_timezone.into());
+    self
+  }
+  pub fn message(mut self, message: &str) -> UpdateDowntimeRequest {
+    self.data.attributes.message = Some(message.into());
+    self
+  }
+  pub fn monitor_id(mut self, monitor_id: u64) -> UpdateDowntimeRequest {
+    self.data.attributes.monitor_identifier = Some(DowntimeMonitorIdentifier::Id { monitor_id });
+    self
+  }
+  pub fn monitor_tags(mut self, monitor_tags: Vec<String>) -> UpdateDowntimeRequest {
+    self.data.attributes.monitor_identifier = Some(DowntimeMonitorIdentifier::Tags { monitor_tags });
+    self
+  }
+  pub fn mute_first_recovery_notification(mut self, mute_first_recovery_notification: bool) -> UpdateDowntimeRequest {
+    self.data.attributes.mute_first_recovery_notification = Some(mute_first_recovery_notification);
+    self
+  }
+  pub fn notify_end_states(mut self, notify_end_states: Vec<NotifyEndState>) -> UpdateDowntimeRequest {
+    self.data.attributes.notify_end_states = Some(notify_end_states);
+    self
+  }
+  pub fn notify_end_types(mut self, notify_end_types: Vec<NotifyEndType>) -> UpdateDowntimeRequest {
+    self.data.attributes.notify_end_types = Some(notify_end_types);
+    self
+  }
+  pub fn schedule(mut self, schedule: DowntimeSchedule) -> UpdateDowntimeRequest {
+    self.data.attributes.schedule = Some(schedule);
+    self
+  }
+  pub fn scope(mut self, scope: &str) -> UpdateDowntimeRequest {
+    self.data.attributes.scope = Some(scope.into());
+    self
+  }
+  /// Mute from now for `duration`, e.g. to extend a downtime.
+  pub fn mute_for(self, duration: Duration) -> UpdateDowntimeRequest {
+    self.schedule(DowntimeSchedule::from_now(duration))
+  }
+}
+
+impl UpdateDowntimeRequest {
+  pub async fn send(&self, client: &Client) -> DatadogResult<DowntimeResponse> {
+    let path_and_query = format!("/api/v2/downtime/{}", &self.downtime_id);
+
+    client
+      .patch::<UpdateDowntimeRequest, DowntimeResponse>(&path_and_query, self)
+      .await
+  }
+}
+
+/// [Get a downtime](https://docs.datadoghq.com/api/latest/downtimes/#get-a-downtime)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GetDowntimeRequest {
+  // Path
+  /// ID of the downtime to fetch.
+  #[serde(skip_serializing)]
+  pub downtime_id: String,
+  // Query
+  /// Comma-separated list of resource paths for related resources to include in the response. Supported resource paths are `created_by` and `monitor`.
+  #[serde(skip_serializing)]
+  pub include: Option<String>,
+}
+impl GetDowntimeRequest {
+  pub fn downtime_id(mut self, downtime_id: &str) -> GetDowntimeRequest {
+    self.downtime_id = downtime_id.into();
+    self
+  }
+  pub fn include(mut self, include: &str) -> GetDowntimeRequest {
+    self.include = Some(include.into());
+    self
+  }
+}
+
+impl GetDowntimeRequest {
+  pub async fn send(&self, client: &Client) -> DatadogResult<DowntimeResponse> {
+    let mut queries: Vec<String> = vec![];
+    if let Some(include) = &self.include {
+      queries.push(format!("include={}", &include));
+    }
+    let path = format!("/api/v2/downtime/{}", &self.downtime_id);
+    let path_and_query = match queries.len() {
+      0 => path.to_string(),
+      _ => format!("{}?{}", &path, queries.join("&")),
+    };
+
+    client
+      .get::<GetDowntimeRequest, DowntimeResponse>(&path_and_query)
+      .await
+  }
+}
+
+/// [Get all downtimes](https://docs.datadoghq.com/api/latest/downtimes/#get-all-downtimes)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListDowntimesRequest {
+  // Query
+  /// Only return downtimes that are active when the request is made.
+  #[serde(skip_serializing)]
+  pub current_only: Option<bool>,
+  /// Comma-separated list of resource paths for related resources to include in the response. Supported resource paths are `created_by` and `monitor`.
+  #[serde(skip_serializing)]
+  pub include: Option<String>,
+  /// Specific offset to use as the beginning of the returned page.
+  #[serde(skip_serializing)]
+  pub page_offset: Option<u64>,
+  /// Maximum number of downtimes in the response. (Defaults to 30)
+  #[serde(skip_serializing)]
+  pub page_limit: Option<u64>,
+}
+impl ListDowntimesRequest {
+  pub fn current_only(mut self, current_only: bool) -> ListDowntimesRequest {
+    self.current_only = Some(current_only);
+    self
+  }
+  pub fn include(mut self, include: &str) -> ListDowntimesRequest {
+    self.include = Some(include.into());
+    self
+  }
+  pub fn page_offset(mut self, page_offset: u64) -> ListDowntimesRequest {
+    self.page_offset = Some(page_offset);
+    self
+  }
+  pub fn page_limit(mut self, page_limit: u64) -> ListDowntimesRequest {
+    self.page_limit = Some(page_limit);
+    self
+  }
+}
+
+impl ListDowntimesRequest {
+  pub async fn send(&self, client: &Client) -> DatadogResult<ListDowntimesResponse> {
+    let mut queries: Vec<String> = vec![];
+    if let Some(current_only) = &self.current_only {
+      queries.push(format!("current_only={}", &current_only));
+    }
+    if let Some(include) = &self.include {
+      queries.push(format!("include={}", &include));
+    }
+    if let Some(page_offset) = &self.page_offset {
+      queries.push(format!("page[offset]={}", &page_offset));
+    }
+    if let Some(page_limit) = &self.page_limit {
+      queries.push(format!("page[limit]={}", &page_limit));
+    }
+    let path = "/api/v2/downtime";
+    let path_and_query = match queries.len() {
+      0 => path.to_string(),
+      _ => format!("{}?{}", &path, queries.join("&")),
+    };
+
+    client
+      .get::<ListDowntimesRequest, ListDowntimesResponse>(&path_and_query)
+      .await
+  }
+
+  pub fn iter<'a>(&'a self, client: &'a Client) -> impl Stream<Item = Downtime> + 'a {
+    AsyncIter::from(async_gen::gen! {
+      let page_limit = self.page_limit.unwrap_or(30);
+      let mut page_offset = self.page_offset.unwrap_or(0);
+      loop {
+        let request = ListDowntimesRequest {
+          current_only: self.current_only,
+          include: self.include.clone(),
+          page_offset: Some(page_offset),
+          page_limit: Some(page_limit),
+        };
+        let result = request.send(client).await.expect("Failed to call .send()");
+        let count = result.data.len() as u64;
+        // Iterate over all results
+        for item in result.data {
+          yield item;
+        }
+        // Stop iterating when we hit the last page
+        if count < page_limit {
+          return;
+        }
+        page_offset += count;
+      }
+    })
+  }
+}
+
+/// [Cancel a downtime](https://docs.datadoghq.com/api/latest/downtimes/#cancel-a-downtime)
+///
+/// Canceled downtimes are kept for about two days before being deleted.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CancelDowntimeRequest {
+  // Path
+  /// ID of the downtime to cancel.
+  #[serde(skip_serializing)]
+  pub downtime_id: String,
+}
+impl CancelDowntimeRequest {
+  pub fn downtime_id(mut self, downtime_id: &str) -> CancelDowntimeRequest {
+    self.downtime_id = downtime_id.into();
+    self
+  }
+}
+
+impl CancelDowntimeRequest {
+  pub async fn send(&self, client: &Client) -> DatadogResult<CancelDowntimeResponse> {
+    let path_and_query = format!("/api/v2/downtime/{}", &self.downtime_id);
+
+    let resp = client.delete_json(&path_and_query, self).await?;
+    match resp.status().is_success() {
+      true => Ok(CancelDowntimeResponse {}),
+      false => {
+        let body = resp.text().await?;
+        Err(Box::new(serde_json::from_str::<DatadogErrorResponse>(&body)?))
+      }
+    }
+  }
+}
+
+/// Response of a successful cancellation. (Always empty)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CancelDowntimeResponse {}
+
+/// Downtime definition as returned by Datadog.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeResponseAttributes {
+  /// Time that the downtime was canceled.
+  pub canceled: Option<String>,
+  /// Creation time of the downtime.
+  pub created: String,
+  /// The timezone in which to display the downtime's start and end times in Datadog applications.
+  pub display_timezone: Option<String>,
+  /// A message to include with notifications for this downtime.
+  pub message: Option<String>,
+  /// Time that the downtime was last modified.
+  pub modified: String,
+  /// The monitors the downtime applies to.
+  pub monitor_identifier: DowntimeMonitorIdentifier,
+  /// If the first recovery notification during a downtime should be muted.
+  pub mute_first_recovery_notification: bool,
+  /// States that re-trigger a monitor notification when the downtime ends.
+  pub notify_end_states: Vec<NotifyEndState>,
+  /// Actions that trigger a monitor notification if the monitor is in a notify end state.
+  pub notify_end_types: Vec<NotifyEndType>,
+  /// When the downtime is active.
+  pub schedule: DowntimeSchedule,
+  /// The scope the downtime applies to.
+  pub scope: String,
+  /// The current status of the downtime. Allowed enum values: `active`, `canceled`, `ended`, `scheduled`
+  pub status: Option<DowntimeStatus>,
+}
+
+/// A reference to a related resource.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeRelationshipData {
+  pub id: Value,
+  #[serde(rename = "type")]
+  pub _type: String,
+}
+
+/// A related resource.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeRelationship {
+  pub data: Option<DowntimeRelationshipData>,
+}
+
+/// Resources related to a downtime.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeRelationships {
+  /// The user who created the downtime.
+  pub created_by: DowntimeRelationship,
+  /// The monitor identified by the downtime.
+  pub monitor: DowntimeRelationship,
+}
+
+/// A downtime.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Downtime {
+  /// Downtime definition.
+  pub attributes: DowntimeResponseAttributes,
+  /// The downtime ID.
+  pub id: String,
+  /// Resources related to the downtime.
+  pub relationships: DowntimeRelationships,
+  /// Downtime resource type. Allowed enum values: `downtime`
+  #[serde(rename = "type")]
+  pub _type: String,
+}
+
+/// A downtime, with the related resources requested with `include`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DowntimeResponse {
+  pub data: Downtime,
+  /// Users and monitors related to the downtime.
+  pub included: Vec<Value>,
+}
+
+/// Pagination metadata.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListDowntimesMetaPage {
+  /// Total count of elements matched by the filter.
+  pub total_filtered_count: u64,
+}
+
+/// Pagination metadata.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListDowntimesMeta {
+  pub page: ListDowntimesMetaPage,
+}
+
+/// A page of downtimes.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListDowntimesResponse {
+  pub data: Vec<Downtime>,
+  /// Users and monitors related to the downtimes.
+  pub included: Vec<Value>,
+  pub meta: ListDowntimesMeta,
+}
@@ -3,7 +3,9 @@ mod client;
 pub mod authentication;
 pub mod dashboard_lists;
 pub mod dashboards;
+pub mod downtimes;
 pub mod logs;
 pub mod monitors;
 pub mod security_monitoring;
+pub mod terraform;
 pub use client::{*};
@@ -0,0 +1,448 @@
+//! Export every page of a log search to NDJSON, CSV or Parquet files.
+
+use chrono::{DateTime, Duration, SecondsFormat, TimeZone, Utc};
+use futures_util::future::join_all;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::client::{*};
+use super::{Data, Filter, Page, SearchRequest};
+
+/// Maximum number of logs the search endpoint returns per page.
+const MAX_PAGE_LIMIT: u32 = 1000;
+
+/// Columns written to CSV and Parquet files when none are configured.
+const DEFAULT_COLUMNS: [&str; 5] = ["timestamp", "host", "service", "status", "message"];
+
+/// File format of an export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExportFormat {
+  /// One JSON encoded log per line.
+  #[serde(rename="ndjson")]
+  Ndjson,
+  /// One row per log, with the configured attribute columns.
+  #[serde(rename="csv")]
+  Csv,
+  /// Apache Parquet, with the configured attribute columns as UTF-8 columns.
+  #[cfg(feature = "parquet")]
+  #[serde(rename="parquet")]
+  Parquet,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for ExportFormat {
+  fn default() -> ExportFormat {
+    ExportFormat::Ndjson
+  }
+}
+
+/// Progress of a single time range chunk of an export.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChunkCheckpoint {
+  /// The minimum time of the chunk.
+  pub from: Option<String>,
+  /// The maximum time of the chunk.
+  pub to: Option<String>,
+  /// File the chunk is written to.
+  pub path: String,
+  /// Cursor of the next page to fetch, if any.
+  pub cursor: Option<String>,
+  /// Number of logs written so far.
+  pub rows: u64,
+  /// Length of the file after the last completed page.
+  pub bytes: u64,
+  /// Whether every page of the chunk has been written.
+  pub done: bool,
+}
+
+/// Progress of an export, saved after every page so an interrupted export can resume.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportCheckpoint {
+  pub chunks: Vec<ChunkCheckpoint>,
+}
+
+/// Export all logs matching a [`SearchRequest`] to disk, following the cursor until the last page.
+///
+/// Set `chunks` to split the filter's time range into that many sub-ranges which are fetched concurrently and written to
+/// one file each, e.g. `logs.ndjson` becomes `logs.0.ndjson`, `logs.1.ndjson`, ... Splitting requires `from` and `to`
+/// to be RFC3339 timestamps, epoch milliseconds, `now` or `now-<n><s|m|h|d|w>`.
+///
+/// When a `checkpoint` file is set, progress is saved to it after every page and an existing checkpoint is resumed from.
+/// NDJSON and CSV files are truncated back to the last completed page; Parquet chunks that did not finish are restarted.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExportRequest {
+  /// The search to export. The sort and filter are kept; the page limit defaults to the maximum.
+  pub search: SearchRequest,
+  /// File format of the export.
+  pub format: ExportFormat,
+  /// Attributes of `Data.attributes` written as CSV or Parquet columns, using `.` to reach nested attributes.
+  pub columns: Vec<String>,
+  /// File to write the logs to.
+  pub path: String,
+  /// File to save progress to.
+  pub checkpoint: Option<String>,
+  /// Number of time range chunks to fetch concurrently.
+  pub chunks: Option<u32>,
+}
+
+impl ExportRequest {
+  pub fn search(mut self, search: SearchRequest) -> ExportRequest {
+    self.search = search;
+    self
+  }
+  pub fn format(mut self, format: ExportFormat) -> ExportRequest {
+    self.format = format;
+    self
+  }
+  pub fn columns(mut self, columns: Vec<String>) -> ExportRequest {
+    self.columns = columns;
+    self
+  }
+  pub fn path(mut self, path: &str) -> ExportRequest {
+    self.path = path.into();
+    self
+  }
+  pub fn checkpoint(mut self, checkpoint: &str) -> ExportRequest {
+    self.checkpoint = Some(checkpoint.into());
+    self
+  }
+  pub fn chunks(mut self, chunks: u32) -> ExportRequest {
+    self.chunks = Some(chunks);
+    self
+  }
+
+  pub async fn send(&self, client: &Client) -> DatadogResult<ExportResponse> {
+    let state = Mutex::new(self.load_checkpoint()?);
+    let count = state.lock().unwrap().chunks.len();
+    let results = join_all((0..count).map(|index| self.export_chunk(client, &state, index))).await;
+    for result in results {
+      result?;
+    }
+
+    let state = state.into_inner().unwrap();
+    Ok(ExportResponse {
+      files: state.chunks.iter().map(|chunk| chunk.path.clone()).collect(),
+      rows: state.chunks.iter().map(|chunk| chunk.rows).sum(),
+    })
+  }
+
+  /// Columns written to CSV and Parquet files.
+  fn column_names(&self) -> Vec<String> {
+    match self.columns.len() {
+      0 => DEFAULT_COLUMNS.iter().map(|column| column.to_string()).collect(),
+      _ => self.columns.clone(),
+    }
+  }
+
+  /// Resume from the checkpoint file if there is one, otherwise plan the chunks from scratch.
+  fn load_checkpoint(&self) -> DatadogResult<ExportCheckpoint> {
+    if let Some(checkpoint) = &self.checkpoint {
+      if Path::new(checkpoint).exists() {
+        let state = serde_json::from_str::<ExportCheckpoint>(&fs::read_to_string(checkpoint)?)?;
+        if state.chunks.len() != self.chunks.unwrap_or(1).max(1) as usize {
+          return Err(format!("Checkpoint {} was written for a different number of chunks", checkpoint).into());
+        }
+        return Ok(state);
+      }
+    }
+
+    let filter = self.search.filter.clone().unwrap_or_default();
+    let chunks = match self.chunks.unwrap_or(1) {
+      0 | 1 => vec![ChunkCheckpoint {
+        from: filter.from,
+        to: filter.to,
+        path: self.path.clone(),
+        ..Default::default()
+      }],
+      count => {
+        let now = Utc::now();
+        let from = parse_time(filter.from.as_deref().unwrap_or("now-15m"), now)?;
+        let to = parse_time(filter.to.as_deref().unwrap_or("now"), now)?;
+        split_time_range(from, to, count)
+          .into_iter()
+          .enumerate()
+          .map(|(index, (from, to))| ChunkCheckpoint {
+            from: Some(from.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            to: Some(to.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            path: chunk_path(&self.path, index),
+            ..Default::default()
+          })
+          .collect()
+      }
+    };
+    Ok(ExportCheckpoint { chunks })
+  }
+
+  fn save_checkpoint(&self, state: &Mutex<ExportCheckpoint>) -> DatadogResult<()> {
+    if let Some(checkpoint) = &self.checkpoint {
+      let json = serde_json::to_string_pretty(&*state.lock().unwrap())?;
+      let tmp = format!("{}.tmp", checkpoint);
+      fs::write(&tmp, json)?;
+      fs::rename(&tmp, checkpoint)?;
+    }
+    Ok(())
+  }
+
+  async fn export_chunk(&self, client: &Client, state: &Mutex<ExportCheckpoint>, index: usize) -> DatadogResult<()> {
+    let chunk = state.lock().unwrap().chunks[index].clone();
+    if chunk.done {
+      return Ok(());
+    }
+    let columns = self.column_names();
+    let mut writer = ChunkWriter::open(&self.format, &chunk, &columns)?;
+    let mut cursor = match writer.resumable() {
+      true => chunk.cursor.clone(),
+      false => None,
+    };
+    let mut rows = match writer.resumable() {
+      true => chunk.rows,
+      false => 0,
+    };
+
+    loop {
+      let filter = self.search.filter.clone().unwrap_or_default();
+      let filter = Filter { from: chunk.from.clone(), to: chunk.to.clone(), ..filter };
+      let page = self.search.page.clone().unwrap_or_default();
+      let page = Page {
+        cursor: cursor.clone(),
+        limit: Some(page.limit.unwrap_or(MAX_PAGE_LIMIT)),
+      };
+      let request = SearchRequest {
+        filter: Some(filter),
+        page: Some(page),
+        ..self.search.clone()
+      };
+      let response = request.send(client).await?;
+      writer.write(&response.data, &columns)?;
+      rows += response.data.len() as u64;
+      cursor = response.meta.page.map(|page| page.after).filter(|after| !after.is_empty());
+
+      let bytes = writer.flush()?;
+      {
+        let mut state = state.lock().unwrap();
+        let chunk = &mut state.chunks[index];
+        chunk.cursor = cursor.clone();
+        chunk.rows = rows;
+        chunk.bytes = bytes;
+        chunk.done = cursor.is_none() && writer.resumable();
+      }
+      if cursor.is_none() {
+        break;
+      }
+      self.save_checkpoint(state)?;
+    }
+
+    writer.close()?;
+    state.lock().unwrap().chunks[index].done = true;
+    self.save_checkpoint(state)
+  }
+}
+
+/// Files written by an export.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportResponse {
+  /// Files the logs were written to, one per chunk.
+  pub files: Vec<String>,
+  /// Total number of logs written.
+  pub rows: u64,
+}
+
+enum ChunkWriter {
+  Ndjson(BufWriter<File>),
+  Csv(Box<csv::Writer<File>>),
+  #[cfg(feature = "parquet")]
+  Parquet(Box<parquet::arrow::ArrowWriter<File>>, arrow_schema::SchemaRef),
+}
+
+impl ChunkWriter {
+  fn open(format: &ExportFormat, chunk: &ChunkCheckpoint, columns: &[String]) -> DatadogResult<ChunkWriter> {
+    match format {
+      ExportFormat::Ndjson => Ok(ChunkWriter::Ndjson(BufWriter::new(open_truncated(&chunk.path, chunk.bytes)?))),
+      ExportFormat::Csv => {
+        let file = open_truncated(&chunk.path, chunk.bytes)?;
+        let mut writer = csv::Writer::from_writer(file);
+        if chunk.bytes == 0 {
+          let mut header = vec!["id".to_string()];
+          header.extend(columns.iter().cloned());
+          writer.write_record(&header)?;
+        }
+        Ok(ChunkWriter::Csv(Box::new(writer)))
+      }
+      #[cfg(feature = "parquet")]
+      ExportFormat::Parquet => {
+        let file = File::create(&chunk.path)?;
+        let schema = parquet_schema(columns);
+        let writer = parquet::arrow::ArrowWriter::try_new(file, schema.clone(), None)?;
+        Ok(ChunkWriter::Parquet(Box::new(writer), schema))
+      }
+    }
+  }
+
+  /// Whether the file can be appended to after an interruption.
+  fn resumable(&self) -> bool {
+    match self {
+      ChunkWriter::Ndjson(_) | ChunkWriter::Csv(_) => true,
+      #[cfg(feature = "parquet")]
+      ChunkWriter::Parquet(_, _) => false,
+    }
+  }
+
+  fn write(&mut self, data: &[Data], columns: &[String]) -> DatadogResult<()> {
+    match self {
+      ChunkWriter::Ndjson(writer) => {
+        for log in data {
+          serde_json::to_writer(&mut *writer, log)?;
+          writer.write_all(b"\n")?;
+        }
+      }
+      ChunkWriter::Csv(writer) => {
+        for log in data {
+          let mut record = vec![log.id.clone()];
+          record.extend(columns.iter().map(|column| log.column(column)));
+          writer.write_record(&record)?;
+        }
+      }
+      #[cfg(feature = "parquet")]
+      ChunkWriter::Parquet(writer, schema) => {
+        use arrow_array::{ArrayRef, RecordBatch, StringArray};
+        use std::sync::Arc;
+
+        let mut arrays: Vec<ArrayRef> = vec![Arc::new(StringArray::from_iter_values(data.iter().map(|log| log.id.clone())))];
+        for column in columns {
+          arrays.push(Arc::new(StringArray::from_iter_values(data.iter().map(|log| log.column(column)))));
+        }
+        let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+        writer.write(&batch)?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Flush buffered rows to disk, returning the length of the file.
+  fn flush(&mut self) -> DatadogResult<u64> {
+    match self {
+      ChunkWriter::Ndjson(writer) => {
+        writer.flush()?;
+        Ok(writer.get_ref().metadata()?.len())
+      }
+      ChunkWriter::Csv(writer) => {
+        writer.flush()?;
+        Ok(writer.get_ref().metadata()?.len())
+      }
+      #[cfg(feature = "parquet")]
+      ChunkWriter::Parquet(writer, _) => {
+        writer.flush()?;
+        Ok(writer.bytes_written() as u64)
+      }
+    }
+  }
+
+  fn close(self) -> DatadogResult<()> {
+    match self {
+      ChunkWriter::Ndjson(mut writer) => writer.flush()?,
+      ChunkWriter::Csv(mut writer) => writer.flush()?,
+      #[cfg(feature = "parquet")]
+      ChunkWriter::Parquet(writer, _) => {
+        writer.close()?;
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_schema(columns: &[String]) -> arrow_schema::SchemaRef {
+  use arrow_schema::{DataType, Field, Schema};
+
+  let mut fields = vec![Field::new("id", DataType::Utf8, false)];
+  fields.extend(columns.iter().map(|column| Field::new(column, DataType::Utf8, false)));
+  std::sync::Arc::new(Schema::new(fields))
+}
+
+/// Open a file for appending, dropping anything written after `len` bytes.
+fn open_truncated(path: &str, len: u64) -> DatadogResult<File> {
+  let file = OpenOptions::new().create(true).append(true).open(path)?;
+  file.set_len(len)?;
+  Ok(file)
+}
+
+/// Insert the chunk index before the file extension, e.g. `logs.ndjson` becomes `logs.3.ndjson`.
+fn chunk_path(path: &str, index: usize) -> String {
+  let path = Path::new(path);
+  let stem = path.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
+  let name = match path.extension() {
+    Some(extension) => format!("{}.{}.{}", stem, index, extension.to_string_lossy()),
+    None => format!("{}.{}", stem, index),
+  };
+  path.with_file_name(name).to_string_lossy().into_owned()
+}
+
+/// Parse an absolute time or a simple `now-<n><unit>` date math expression.
+///
+/// Supports RFC3339 timestamps, epoch milliseconds, `now` and `now-<n>` with the units `s`, `m`, `h`, `d` and `w`.
+pub fn parse_time(time: &str, now: DateTime<Utc>) -> DatadogResult<DateTime<Utc>> {
+  let time = time.trim();
+  if time == "now" {
+    return Ok(now);
+  }
+  if let Some(offset) = time.strip_prefix("now-") {
+    let invalid = || format!("Invalid date math: {}", time);
+    let unit = offset.chars().next_back().ok_or_else(invalid)?;
+    let amount: i64 = offset[..offset.len() - unit.len_utf8()].parse().map_err(|_| invalid())?;
+    let duration = match unit {
+      's' => Duration::try_seconds(amount),
+      'm' => Duration::try_minutes(amount),
+      'h' => Duration::try_hours(amount),
+      'd' => Duration::try_days(amount),
+      'w' => Duration::try_weeks(amount),
+      _ => return Err(format!("Unsupported date math unit: {}", time).into()),
+    };
+    return duration
+      .and_then(|duration| now.checked_sub_signed(duration))
+      .ok_or_else(|| format!("Date math out of range: {}", time).into());
+  }
+  if let Ok(millis) = time.parse::<i64>() {
+    return Utc.timestamp_millis_opt(millis).single().ok_or_else(|| format!("Invalid timestamp: {}", time).into());
+  }
+  Ok(DateTime::parse_from_rfc3339(time)?.with_timezone(&Utc))
+}
+
+/// Split a time range into `chunks` contiguous sub-ranges of (almost) equal length.
+pub fn split_time_range(from: DateTime<Utc>, to: DateTime<Utc>, chunks: u32) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+  let chunks = chunks.max(1) as i64;
+  let step = (to - from).num_milliseconds().max(0) / chunks;
+  (0..chunks)
+    .map(|index| {
+      let start = from + Duration::milliseconds(step * index);
+      let end = match index == chunks - 1 {
+        true => to,
+        false => from + Duration::milliseconds(step * (index + 1)),
+      };
+      (start, end)
+    })
+    .collect()
+}
+
+impl Data {
+  /// Value of an attribute as a flat string, using `.` to reach nested attributes.
+  ///
+  /// Strings are returned as-is, missing attributes and nulls as an empty string and everything else as JSON.
+  pub fn column(&self, path: &str) -> String {
+    let mut parts = path.split('.');
+    let mut value = parts.next().and_then(|part| self.attributes.get(part));
+    for part in parts {
+      value = value.and_then(|value| value.get(part));
+    }
+    match value {
+      None | Some(Value::Null) => "".to_string(),
+      Some(Value::String(value)) => value.clone(),
+      Some(value) => value.to_string(),
+    }
+  }
+}
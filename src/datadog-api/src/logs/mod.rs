@@ -1,5 +1,8 @@
 //! Search your logs and send them to your Datadog platform over HTTP.
 
+mod export;
+
+pub use export::*;
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -0,0 +1,287 @@
+//! Composite monitor expressions, e.g. `12345 && (67890 || !11111)`.
+use crate::client::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use super::query::Parser;
+use super::{CreateMonitorRequest, GetMonitorRequest, GetMonitorResponse, Monitor, MonitorType, MonitorValidationError, MonitorValidationErrors, QueryParseError};
+
+/// A boolean expression over other monitors, used as the query of a `composite` monitor.
+///
+/// Combine monitor references with `&`, `|` and `!`:
+/// `CompositeExpression::from(12345) & (CompositeExpression::from(67890) | !CompositeExpression::from(11111))`
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompositeExpression {
+    /// A reference to a monitor by ID.
+    Monitor(u64),
+    /// `a && b`
+    And(Box<CompositeExpression>, Box<CompositeExpression>),
+    /// `a || b`
+    Or(Box<CompositeExpression>, Box<CompositeExpression>),
+    /// `!a`
+    Not(Box<CompositeExpression>),
+}
+
+impl CompositeExpression {
+    pub fn monitor(monitor_id: u64) -> CompositeExpression {
+        CompositeExpression::Monitor(monitor_id)
+    }
+    pub fn and<T: Into<CompositeExpression>>(self, other: T) -> CompositeExpression {
+        CompositeExpression::And(Box::new(self), Box::new(other.into()))
+    }
+    pub fn or<T: Into<CompositeExpression>>(self, other: T) -> CompositeExpression {
+        CompositeExpression::Or(Box::new(self), Box::new(other.into()))
+    }
+
+    /// IDs of the referenced monitors, in order of first appearance.
+    pub fn monitor_ids(&self) -> Vec<u64> {
+        let mut ids = vec![];
+        self.collect_ids(&mut ids);
+        ids
+    }
+
+    fn collect_ids(&self, ids: &mut Vec<u64>) {
+        match self {
+            CompositeExpression::Monitor(id) => {
+                if !ids.contains(id) {
+                    ids.push(*id);
+                }
+            }
+            CompositeExpression::And(left, right) | CompositeExpression::Or(left, right) => {
+                left.collect_ids(ids);
+                right.collect_ids(ids);
+            }
+            CompositeExpression::Not(inner) => inner.collect_ids(ids),
+        }
+    }
+
+    /// Parse a composite monitor query.
+    pub fn parse(query: &str) -> Result<CompositeExpression, QueryParseError> {
+        let mut parser = Parser::new(query);
+        let expression = or_expression(&mut parser)?;
+        parser.end()?;
+        Ok(expression)
+    }
+
+    /// Check that every referenced monitor is in `monitors`, and that no composite monitor ends up referencing itself.
+    ///
+    /// `monitors` must hold every monitor reachable from the expression, e.g. the result of listing all monitors.
+    /// `monitor_id` is the ID of the composite monitor being edited, if any.
+    pub fn check_against(&self, monitor_id: Option<u64>, monitors: &[GetMonitorResponse]) -> Result<(), MonitorValidationErrors> {
+        let monitors: HashMap<u64, &GetMonitorResponse> = monitors.iter().map(|monitor| (monitor.id, monitor)).collect();
+        let mut errors: Vec<MonitorValidationError> = self
+            .monitor_ids()
+            .into_iter()
+            .filter(|id| !monitors.contains_key(id) && Some(*id) != monitor_id)
+            .map(|id| MonitorValidationError {
+                field: Some("query".into()),
+                message: format!("Monitor {} does not exist", id),
+            })
+            .collect();
+
+        let mut graph: HashMap<u64, Vec<u64>> = monitors
+            .values()
+            .filter(|monitor| monitor._type == MonitorType::Composite)
+            .filter_map(|monitor| CompositeExpression::parse(&monitor.query).ok().map(|expression| (monitor.id, expression.monitor_ids())))
+            .collect();
+        // The submitted expression replaces whatever the edited monitor referenced before.
+        let root = monitor_id.unwrap_or(0);
+        graph.insert(root, self.monitor_ids());
+        if let Some(cycle) = find_cycle(&graph, root) {
+            let path: Vec<String> = cycle
+                .iter()
+                .map(|id| match *id == root && monitor_id.is_none() {
+                    true => "new monitor".to_string(),
+                    false => id.to_string(),
+                })
+                .collect();
+            errors.push(MonitorValidationError {
+                field: Some("query".into()),
+                message: format!("Composite monitors reference each other in a cycle: {}", path.join(" -> ")),
+            });
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(MonitorValidationErrors { errors }),
+        }
+    }
+
+    /// Fetch every monitor reachable from the expression and check it with [`CompositeExpression::check_against`].
+    ///
+    /// Returns [`MonitorValidationErrors`] as the error when a monitor does not exist or references form a cycle.
+    pub async fn check(&self, client: &Client, monitor_id: Option<u64>) -> DatadogResult<()> {
+        let mut monitors: Vec<GetMonitorResponse> = vec![];
+        let mut seen: HashSet<u64> = monitor_id.into_iter().collect();
+        let mut pending = self.monitor_ids();
+        while let Some(id) = pending.pop() {
+            if !seen.insert(id) {
+                continue;
+            }
+            if let Some(monitor) = get_monitor(client, id).await? {
+                if monitor._type == MonitorType::Composite {
+                    if let Ok(expression) = CompositeExpression::parse(&monitor.query) {
+                        pending.extend(expression.monitor_ids());
+                    }
+                }
+                monitors.push(monitor);
+            }
+        }
+        self.check_against(monitor_id, &monitors)?;
+        Ok(())
+    }
+
+    /// Check the expression, then build a `composite` monitor request from it.
+    pub async fn request(&self, client: &Client) -> DatadogResult<CreateMonitorRequest> {
+        self.check(client, None).await?;
+        Ok(CreateMonitorRequest::default().composite(self))
+    }
+}
+
+/// Get a monitor, or `None` when it does not exist.
+async fn get_monitor(client: &Client, monitor_id: u64) -> DatadogResult<Option<GetMonitorResponse>> {
+    match GetMonitorRequest::default().monitor_id(monitor_id).send(client).await {
+        Ok(monitor) => Ok(Some(monitor)),
+        Err(error) => match error.downcast_ref::<DatadogErrorResponse>() {
+            Some(response) if response.is_not_found() => Ok(None),
+            _ => Err(error),
+        },
+    }
+}
+
+/// Depth-first search for a path from `root` back to any monitor already on the path.
+fn find_cycle(graph: &HashMap<u64, Vec<u64>>, root: u64) -> Option<Vec<u64>> {
+    fn visit(graph: &HashMap<u64, Vec<u64>>, id: u64, path: &mut Vec<u64>, done: &mut HashSet<u64>) -> Option<Vec<u64>> {
+        if let Some(start) = path.iter().position(|visited| *visited == id) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(id);
+            return Some(cycle);
+        }
+        if done.contains(&id) {
+            return None;
+        }
+        path.push(id);
+        for child in graph.get(&id).into_iter().flatten() {
+            if let Some(cycle) = visit(graph, *child, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(id);
+        None
+    }
+    visit(graph, root, &mut vec![], &mut HashSet::new())
+}
+
+fn or_expression(parser: &mut Parser) -> Result<CompositeExpression, QueryParseError> {
+    let mut expression = and_expression(parser)?;
+    while parser.eat("||") {
+        expression = expression.or(and_expression(parser)?);
+    }
+    Ok(expression)
+}
+
+fn and_expression(parser: &mut Parser) -> Result<CompositeExpression, QueryParseError> {
+    let mut expression = unary_expression(parser)?;
+    while parser.eat("&&") {
+        expression = expression.and(unary_expression(parser)?);
+    }
+    Ok(expression)
+}
+
+fn unary_expression(parser: &mut Parser) -> Result<CompositeExpression, QueryParseError> {
+    if parser.eat("!") {
+        return Ok(!unary_expression(parser)?);
+    }
+    if parser.eat("(") {
+        let expression = or_expression(parser)?;
+        parser.expect(")")?;
+        return Ok(expression);
+    }
+    let id = parser.take_while(|c| c.is_ascii_digit());
+    id.parse::<u64>()
+        .map(CompositeExpression::Monitor)
+        .map_err(|_| parser.error("Expected a monitor ID"))
+}
+
+impl CreateMonitorRequest {
+    /// Set the query and monitor type of a `composite` monitor.
+    pub fn composite(mut self, expression: &CompositeExpression) -> CreateMonitorRequest {
+        self.query = expression.to_string();
+        self._type = MonitorType::Composite;
+        self
+    }
+}
+
+impl From<u64> for CompositeExpression {
+    fn from(monitor_id: u64) -> CompositeExpression {
+        CompositeExpression::Monitor(monitor_id)
+    }
+}
+
+impl From<&GetMonitorResponse> for CompositeExpression {
+    fn from(monitor: &GetMonitorResponse) -> CompositeExpression {
+        CompositeExpression::Monitor(monitor.id)
+    }
+}
+
+impl From<&Monitor> for CompositeExpression {
+    fn from(monitor: &Monitor) -> CompositeExpression {
+        CompositeExpression::Monitor(monitor.id)
+    }
+}
+
+impl<T: Into<CompositeExpression>> ops::BitAnd<T> for CompositeExpression {
+    type Output = CompositeExpression;
+    fn bitand(self, other: T) -> CompositeExpression {
+        self.and(other)
+    }
+}
+
+impl<T: Into<CompositeExpression>> ops::BitOr<T> for CompositeExpression {
+    type Output = CompositeExpression;
+    fn bitor(self, other: T) -> CompositeExpression {
+        self.or(other)
+    }
+}
+
+impl ops::Not for CompositeExpression {
+    type Output = CompositeExpression;
+    fn not(self) -> CompositeExpression {
+        CompositeExpression::Not(Box::new(self))
+    }
+}
+
+impl FromStr for CompositeExpression {
+    type Err = QueryParseError;
+    fn from_str(query: &str) -> Result<CompositeExpression, QueryParseError> {
+        CompositeExpression::parse(query)
+    }
+}
+
+impl fmt::Display for CompositeExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompositeExpression::Monitor(id) => write!(f, "{}", id),
+            CompositeExpression::Or(left, right) => write!(f, "{} || {}", left, right),
+            CompositeExpression::And(left, right) => {
+                for (i, side) in [left, right].iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " && ")?;
+                    }
+                    match side.as_ref() {
+                        CompositeExpression::Or(_, _) => write!(f, "({})", side)?,
+                        _ => write!(f, "{}", side)?,
+                    }
+                }
+                Ok(())
+            }
+            CompositeExpression::Not(inner) => match inner.as_ref() {
+                CompositeExpression::And(_, _) | CompositeExpression::Or(_, _) => write!(f, "!({})", inner),
+                _ => write!(f, "!{}", inner),
+            },
+        }
+    }
+}
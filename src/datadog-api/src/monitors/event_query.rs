@@ -0,0 +1,275 @@
+//! Typed queries for monitors on the event platform: logs, APM trace analytics, RUM, events and audit trail.
+//!
+//! e.g. `logs("service:api status:error").index("*").rollup("count").by("host").last("5m") > 100`
+use std::fmt;
+use std::str::FromStr;
+
+use super::query::{check_thresholds, Parser};
+use super::{Comparator, CreateMonitorRequest, MonitorType, QueryParseError, ThresholdValue, Thresholds};
+
+/// The data an event platform monitor queries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventQuerySource {
+    /// `logs(...)`, for `log alert` monitors.
+    Logs,
+    /// `trace-analytics(...)`, for `trace-analytics alert` monitors.
+    TraceAnalytics,
+    /// `rum(...)`, for `rum alert` monitors.
+    Rum,
+    /// `events(...)`, for `event-v2 alert` monitors.
+    Events,
+    /// `audits(...)`, for `audit alert` monitors.
+    Audits,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for EventQuerySource {
+    fn default() -> EventQuerySource {
+        EventQuerySource::Logs
+    }
+}
+impl EventQuerySource {
+    /// The monitor type that uses this query dialect.
+    pub fn monitor_type(&self) -> MonitorType {
+        match self {
+            EventQuerySource::Logs => MonitorType::LogAlert,
+            EventQuerySource::TraceAnalytics => MonitorType::TraceAnalyticsAlert,
+            EventQuerySource::Rum => MonitorType::RumAlert,
+            EventQuerySource::Events => MonitorType::EventV2Alert,
+            EventQuerySource::Audits => MonitorType::AuditAlert,
+        }
+    }
+}
+
+impl fmt::Display for EventQuerySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EventQuerySource::Logs => "logs",
+            EventQuerySource::TraceAnalytics => "trace-analytics",
+            EventQuerySource::Rum => "rum",
+            EventQuerySource::Events => "events",
+            EventQuerySource::Audits => "audits",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// How matching events are aggregated, e.g. `.rollup("count")` or `.rollup("avg", "@duration")`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRollup {
+    /// Allowed values: `count`, `cardinality`, `avg`, `sum`, `min`, `max`, `median`, `pc75`, `pc90`, `pc95`, `pc98`, `pc99`
+    pub aggregation: String,
+    /// The attribute to aggregate over. Required for everything but `count`.
+    pub measure: Option<String>,
+}
+impl Default for EventRollup {
+    fn default() -> EventRollup {
+        EventRollup {
+            aggregation: "count".into(),
+            measure: None,
+        }
+    }
+}
+
+/// A query of a `log alert`, `trace-analytics alert`, `rum alert`, `event-v2 alert` or `audit alert` monitor.
+///
+/// Build one with the builder methods and `to_string()` it, or parse an existing query with `parse()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventMonitorQuery {
+    /// The data the monitor queries.
+    pub source: EventQuerySource,
+    /// The search query, following the search syntax of the source.
+    pub search: String,
+    /// The indexes to search. Empty means the default indexes.
+    pub indexes: Vec<String>,
+    /// How matching events are aggregated.
+    pub rollup: EventRollup,
+    /// Facets the monitor is broken down on.
+    pub group_by: Vec<String>,
+    /// The evaluation window, e.g. `5m`.
+    pub window: String,
+    /// Comparator between the evaluated value and the threshold.
+    pub comparator: Comparator,
+    /// The critical threshold, which must match `options.thresholds.critical`.
+    pub threshold: Option<ThresholdValue>,
+}
+impl Default for EventMonitorQuery {
+    fn default() -> EventMonitorQuery {
+        EventMonitorQuery {
+            source: EventQuerySource::Logs,
+            search: "".into(),
+            indexes: vec![],
+            rollup: EventRollup::default(),
+            group_by: vec![],
+            window: "5m".into(),
+            comparator: Comparator::Above,
+            threshold: None,
+        }
+    }
+}
+impl EventMonitorQuery {
+    /// `logs("search")`
+    pub fn logs(search: &str) -> EventMonitorQuery {
+        EventMonitorQuery::default().source(EventQuerySource::Logs).search(search)
+    }
+    /// `trace-analytics("search")`
+    pub fn trace_analytics(search: &str) -> EventMonitorQuery {
+        EventMonitorQuery::default().source(EventQuerySource::TraceAnalytics).search(search)
+    }
+    /// `rum("search")`
+    pub fn rum(search: &str) -> EventMonitorQuery {
+        EventMonitorQuery::default().source(EventQuerySource::Rum).search(search)
+    }
+    /// `events("search")`
+    pub fn events(search: &str) -> EventMonitorQuery {
+        EventMonitorQuery::default().source(EventQuerySource::Events).search(search)
+    }
+    /// `audits("search")`
+    pub fn audits(search: &str) -> EventMonitorQuery {
+        EventMonitorQuery::default().source(EventQuerySource::Audits).search(search)
+    }
+    pub fn source(mut self, source: EventQuerySource) -> EventMonitorQuery {
+        self.source = source;
+        self
+    }
+    pub fn search(mut self, search: &str) -> EventMonitorQuery {
+        self.search = search.into();
+        self
+    }
+    pub fn index(mut self, index: &str) -> EventMonitorQuery {
+        self.indexes.push(index.into());
+        self
+    }
+    pub fn rollup(mut self, aggregation: &str) -> EventMonitorQuery {
+        self.rollup = EventRollup { aggregation: aggregation.into(), measure: None };
+        self
+    }
+    pub fn rollup_measure(mut self, aggregation: &str, measure: &str) -> EventMonitorQuery {
+        self.rollup = EventRollup { aggregation: aggregation.into(), measure: Some(measure.into()) };
+        self
+    }
+    pub fn by(mut self, facet: &str) -> EventMonitorQuery {
+        self.group_by.push(facet.into());
+        self
+    }
+    pub fn last(mut self, window: &str) -> EventMonitorQuery {
+        self.window = window.into();
+        self
+    }
+    pub fn comparator(mut self, comparator: Comparator) -> EventMonitorQuery {
+        self.comparator = comparator;
+        self
+    }
+    pub fn threshold<T: Into<ThresholdValue>>(mut self, threshold: T) -> EventMonitorQuery {
+        self.threshold = Some(threshold.into());
+        self
+    }
+
+    /// The monitor type that uses this query.
+    pub fn monitor_type(&self) -> MonitorType {
+        self.source.monitor_type()
+    }
+
+    /// Parse an event platform monitor query string.
+    pub fn parse(query: &str) -> Result<EventMonitorQuery, QueryParseError> {
+        let mut parser = Parser::new(query);
+        let source = match parser.take_while(|c| c.is_ascii_alphanumeric() || c == '-') {
+            "logs" => EventQuerySource::Logs,
+            "trace-analytics" => EventQuerySource::TraceAnalytics,
+            "rum" => EventQuerySource::Rum,
+            "events" => EventQuerySource::Events,
+            "audits" => EventQuerySource::Audits,
+            name => return Err(parser.error(&format!("Unknown query source `{}`", name))),
+        };
+        parser.expect("(")?;
+        let search = parser.escaped()?;
+        parser.expect(")")?;
+
+        let mut query = EventMonitorQuery::default().source(source).search(&search);
+        while parser.eat(".") {
+            let method = parser.identifier()?;
+            parser.expect("(")?;
+            let mut args = vec![];
+            if !parser.eat(")") {
+                loop {
+                    args.push(parser.escaped()?);
+                    if parser.eat(")") {
+                        break;
+                    }
+                    parser.expect(",")?;
+                }
+            }
+            match (method, args.as_slice()) {
+                ("index", [indexes]) => query.indexes = split_list(indexes),
+                ("rollup", [aggregation]) => query = query.rollup(aggregation),
+                ("rollup", [aggregation, measure]) => query = query.rollup_measure(aggregation, measure),
+                ("by", [facets]) => query.group_by = split_list(facets),
+                ("last", [window]) => query.window = window.clone(),
+                (method, _) => return Err(parser.error(&format!("Unexpected `.{}` arguments", method))),
+            }
+        }
+
+        parser.skip_whitespace();
+        if parser.peek().is_some() {
+            query.comparator = parser.comparator()?;
+            query.threshold = Some(parser.number()?);
+        }
+        parser.end()?;
+        Ok(query)
+    }
+
+    /// Check the query's threshold against the monitor thresholds.
+    ///
+    /// `critical` must equal the query threshold, and `warning` must trigger before `critical` given the comparator.
+    pub fn check_thresholds(&self, thresholds: &Thresholds) -> Result<(), String> {
+        check_thresholds(self.comparator, self.threshold, thresholds)
+    }
+}
+
+impl CreateMonitorRequest {
+    /// Set the query and monitor type from a typed event platform query, setting `options.thresholds.critical` to its threshold if not set yet.
+    pub fn event_query(mut self, query: &EventMonitorQuery) -> CreateMonitorRequest {
+        self.query = query.to_string();
+        self._type = query.monitor_type();
+        self.default_critical(query.threshold)
+    }
+}
+
+impl FromStr for EventMonitorQuery {
+    type Err = QueryParseError;
+    fn from_str(query: &str) -> Result<EventMonitorQuery, QueryParseError> {
+        EventMonitorQuery::parse(query)
+    }
+}
+
+impl fmt::Display for EventMonitorQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.source, escape(&self.search))?;
+        if !self.indexes.is_empty() {
+            write!(f, ".index({})", escape(&self.indexes.join(",")))?;
+        }
+        match &self.rollup.measure {
+            Some(measure) => write!(f, ".rollup({}, {})", escape(&self.rollup.aggregation), escape(measure))?,
+            None => write!(f, ".rollup({})", escape(&self.rollup.aggregation))?,
+        }
+        if !self.group_by.is_empty() {
+            write!(f, ".by({})", escape(&self.group_by.join(",")))?;
+        }
+        write!(f, ".last({})", escape(&self.window))?;
+        if let Some(threshold) = &self.threshold {
+            write!(f, " {} {}", self.comparator, threshold)?;
+        }
+        Ok(())
+    }
+}
+
+/// Double quote a string, escaping quotes and backslashes.
+fn escape(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
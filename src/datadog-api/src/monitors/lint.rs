@@ -0,0 +1,282 @@
+//! Lint monitor definitions against policy rules, offline on monitor files or online across the monitors of an organization.
+use crate::client::*;
+use std::fmt;
+use std::path::Path;
+
+use super::{CreateMonitorRequest, GetMonitorRequest, GetMonitorResponse, ListMonitorsRequest, MonitorMessage, MonitorType, MonitorsFile, MonitorsSearchRequest};
+
+/// How serious a finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A monitor breaking a rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintFinding {
+    /// The name of the broken rule.
+    pub rule: String,
+    pub severity: Severity,
+    /// The ID of the monitor, `None` for monitors that only exist in a file.
+    pub monitor_id: Option<u64>,
+    /// The name of the monitor.
+    pub monitor_name: String,
+    /// What is wrong.
+    pub message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}] `{}`", self.severity, self.rule, self.monitor_name)?;
+        if let Some(monitor_id) = self.monitor_id {
+            write!(f, " ({})", monitor_id)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// A policy monitors must follow.
+pub trait MonitorRule {
+    /// Short identifier of the rule, e.g. `required-priority`.
+    fn name(&self) -> String;
+    fn severity(&self) -> Severity;
+    /// Messages describing how the monitor breaks the rule, empty when it follows the rule.
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String>;
+}
+
+/// Every monitor needs a tag starting with `prefix`, e.g. `team:`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequiredTagRule {
+    pub prefix: String,
+}
+impl MonitorRule for RequiredTagRule {
+    fn name(&self) -> String {
+        format!("required-tag-{}", self.prefix.trim_end_matches(':'))
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        match monitor.tags.iter().flatten().any(|tag| tag.starts_with(&self.prefix)) {
+            true => vec![],
+            false => vec![format!("Missing a `{}` tag", self.prefix)],
+        }
+    }
+}
+
+/// Every monitor needs a priority.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequiredPriorityRule;
+impl MonitorRule for RequiredPriorityRule {
+    fn name(&self) -> String {
+        "required-priority".into()
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        match monitor.priority {
+            Some(_) => vec![],
+            None => vec!["Missing a priority".into()],
+        }
+    }
+}
+
+/// Heartbeat checks, service checks or monitors tagged `heartbeat`, must notify on missing data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeartbeatNoDataRule;
+impl MonitorRule for HeartbeatNoDataRule {
+    fn name(&self) -> String {
+        "heartbeat-notify-no-data".into()
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        let heartbeat = monitor._type == MonitorType::ServiceCheck
+            || monitor.tags.iter().flatten().any(|tag| tag == "heartbeat" || tag.starts_with("heartbeat:"));
+        let notify_no_data = monitor.options.as_ref().and_then(|options| options.notify_no_data).unwrap_or(false);
+        match heartbeat && !notify_no_data {
+            true => vec!["Heartbeat check without `notify_no_data`".into()],
+            false => vec![],
+        }
+    }
+}
+
+/// Priority 1 monitors must renotify while alerting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct P1RenotifyRule;
+impl MonitorRule for P1RenotifyRule {
+    fn name(&self) -> String {
+        "p1-renotify".into()
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        let renotify_interval = monitor.options.as_ref().and_then(|options| options.renotify_interval).unwrap_or(0);
+        match monitor.priority == Some(1) && renotify_interval == 0 {
+            true => vec!["P1 monitor without `renotify_interval`".into()],
+            false => vec![],
+        }
+    }
+}
+
+/// The message must notify someone with an `@` handle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageRoutingRule;
+impl MonitorRule for MessageRoutingRule {
+    fn name(&self) -> String {
+        "message-routing".into()
+    }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        let message = monitor.message.as_deref().unwrap_or("");
+        let routed = message
+            .split(|c: char| c.is_whitespace() || c == '{' || c == '}')
+            .any(|word| word.len() > 1 && word.starts_with('@'));
+        match routed {
+            true => vec![],
+            false => vec!["The message does not notify any `@` handle".into()],
+        }
+    }
+}
+
+/// The message template must be valid for the monitor type, see [`MonitorMessage::validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageTemplateRule;
+impl MonitorRule for MessageTemplateRule {
+    fn name(&self) -> String {
+        "message-template".into()
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        match MonitorMessage::validate(monitor.message.as_deref().unwrap_or(""), &monitor._type) {
+            Ok(()) => vec![],
+            Err(errors) => errors.errors.into_iter().map(|error| error.message).collect(),
+        }
+    }
+}
+
+/// A rule from a closure returning the problem, if any.
+pub struct CustomRule<F: Fn(&CreateMonitorRequest) -> Option<String>> {
+    pub name: String,
+    pub severity: Severity,
+    pub check: F,
+}
+impl<F: Fn(&CreateMonitorRequest) -> Option<String>> MonitorRule for CustomRule<F> {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+    fn check(&self, monitor: &CreateMonitorRequest) -> Vec<String> {
+        (self.check)(monitor).into_iter().collect()
+    }
+}
+
+/// Runs rules over monitor definitions. The default linter runs every built-in rule, with `team:` as required tag.
+pub struct MonitorLinter {
+    pub rules: Vec<Box<dyn MonitorRule>>,
+}
+impl Default for MonitorLinter {
+    fn default() -> MonitorLinter {
+        MonitorLinter::empty()
+            .rule(RequiredTagRule { prefix: "team:".into() })
+            .rule(RequiredPriorityRule)
+            .rule(HeartbeatNoDataRule)
+            .rule(P1RenotifyRule)
+            .rule(MessageRoutingRule)
+            .rule(MessageTemplateRule)
+    }
+}
+impl MonitorLinter {
+    /// A linter without any rule.
+    pub fn empty() -> MonitorLinter {
+        MonitorLinter { rules: vec![] }
+    }
+    pub fn rule<R: MonitorRule + 'static>(mut self, rule: R) -> MonitorLinter {
+        self.rules.push(Box::new(rule));
+        self
+    }
+    /// Add a rule from a closure returning the problem, if any.
+    pub fn custom<F: Fn(&CreateMonitorRequest) -> Option<String> + 'static>(self, name: &str, severity: Severity, check: F) -> MonitorLinter {
+        self.rule(CustomRule {
+            name: name.into(),
+            severity,
+            check,
+        })
+    }
+}
+
+impl MonitorLinter {
+    /// Findings for a monitor definition, most severe first.
+    pub fn lint(&self, monitor: &CreateMonitorRequest) -> Vec<LintFinding> {
+        let mut findings: Vec<LintFinding> = self
+            .rules
+            .iter()
+            .flat_map(|rule| {
+                rule.check(monitor).into_iter().map(move |message| LintFinding {
+                    rule: rule.name(),
+                    severity: rule.severity(),
+                    monitor_id: None,
+                    monitor_name: monitor.name.clone().unwrap_or_default(),
+                    message,
+                })
+            })
+            .collect();
+        findings.sort_by_key(|finding| std::cmp::Reverse(finding.severity));
+        findings
+    }
+
+    /// Findings for an existing monitor.
+    pub fn lint_monitor(&self, monitor: &GetMonitorResponse) -> Vec<LintFinding> {
+        let mut findings = self.lint(&CreateMonitorRequest::from(monitor.clone()));
+        for finding in &mut findings {
+            finding.monitor_id = Some(monitor.id);
+        }
+        findings
+    }
+
+    /// Findings for every monitor of a [`MonitorsFile`].
+    pub fn lint_file<P: AsRef<Path>>(&self, path: P) -> DatadogResult<Vec<LintFinding>> {
+        let file = MonitorsFile::read(path)?;
+        Ok(file.monitors.iter().flat_map(|monitor| self.lint(monitor)).collect())
+    }
+
+    /// Findings for every monitor matching a monitor search query, e.g. `tag:"team:sre"`. An empty query lints every monitor.
+    ///
+    /// A non-empty query fetches only the matching monitors, one request per match.
+    pub async fn lint_search(&self, client: &Client, query: &str) -> DatadogResult<Vec<LintFinding>> {
+        let monitors = match query.is_empty() {
+            true => ListMonitorsRequest::default().send_all(client).await?,
+            false => {
+                let search = MonitorsSearchRequest::default().query(query);
+                let mut monitors = vec![];
+                for found in search.send_all(client).await? {
+                    monitors.push(GetMonitorRequest::default().monitor_id(found.id).send(client).await?);
+                }
+                monitors
+            }
+        };
+        Ok(monitors.iter().flat_map(|monitor| self.lint_monitor(monitor)).collect())
+    }
+}
@@ -0,0 +1,330 @@
+//! Monitor notification messages with conditional blocks, template variables and notification handles.
+//!
+//! e.g. `{{#is_alert}}CPU is {{value}} on {{host.name}} @slack-ops{{/is_alert}}`
+use std::fmt;
+
+use super::{CreateMonitorRequest, MonitorType, MonitorValidationError, MonitorValidationErrors};
+
+/// A condition of a `{{#...}}` block, rendered only when the condition holds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageCondition {
+    /// `is_alert`
+    IsAlert,
+    /// `is_alert_recovery`
+    IsAlertRecovery,
+    /// `is_alert_to_warning`
+    IsAlertToWarning,
+    /// `is_no_data`
+    IsNoData,
+    /// `is_no_data_recovery`
+    IsNoDataRecovery,
+    /// `is_warning`
+    IsWarning,
+    /// `is_warning_recovery`
+    IsWarningRecovery,
+    /// `is_recovery`
+    IsRecovery,
+    /// `is_warning_to_alert`
+    IsWarningToAlert,
+    /// `is_renotify`
+    IsRenotify,
+    /// `is_priority "P1"`
+    IsPriority(String),
+    /// `is_match "variable" "value"`, matching when the variable contains the value.
+    IsMatch(String, String),
+    /// `is_exact_match "variable" "value"`
+    IsExactMatch(String, String),
+}
+
+impl MessageCondition {
+    /// The block name, e.g. `is_alert`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MessageCondition::IsAlert => "is_alert",
+            MessageCondition::IsAlertRecovery => "is_alert_recovery",
+            MessageCondition::IsAlertToWarning => "is_alert_to_warning",
+            MessageCondition::IsNoData => "is_no_data",
+            MessageCondition::IsNoDataRecovery => "is_no_data_recovery",
+            MessageCondition::IsWarning => "is_warning",
+            MessageCondition::IsWarningRecovery => "is_warning_recovery",
+            MessageCondition::IsRecovery => "is_recovery",
+            MessageCondition::IsWarningToAlert => "is_warning_to_alert",
+            MessageCondition::IsRenotify => "is_renotify",
+            MessageCondition::IsPriority(_) => "is_priority",
+            MessageCondition::IsMatch(_, _) => "is_match",
+            MessageCondition::IsExactMatch(_, _) => "is_exact_match",
+        }
+    }
+}
+
+impl fmt::Display for MessageCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageCondition::IsPriority(priority) => write!(f, "{} \"{}\"", self.name(), priority),
+            MessageCondition::IsMatch(variable, value) | MessageCondition::IsExactMatch(variable, value) => {
+                write!(f, "{} \"{}\" \"{}\"", self.name(), variable, value)
+            }
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// Block names accepted by Datadog.
+const CONDITIONS: &[&str] = &[
+    "is_alert",
+    "is_alert_recovery",
+    "is_alert_to_warning",
+    "is_no_data",
+    "is_no_data_recovery",
+    "is_warning",
+    "is_warning_recovery",
+    "is_recovery",
+    "is_warning_to_alert",
+    "is_renotify",
+    "is_priority",
+    "is_match",
+    "is_exact_match",
+];
+
+/// Where a notification is sent, rendered as an `@` mention.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationHandle {
+    /// `@slack-<channel>` or `@slack-<account>-<channel>`
+    Slack(String),
+    /// `@pagerduty-<service>`
+    PagerDuty(String),
+    /// `@opsgenie-<service>`
+    Opsgenie(String),
+    /// `@teams-<channel>`
+    Teams(String),
+    /// `@webhook-<name>`
+    Webhook(String),
+    /// `@<address>`
+    Email(String),
+    /// Any other handle, without the `@`.
+    Other(String),
+}
+
+impl fmt::Display for NotificationHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationHandle::Slack(channel) => write!(f, "@slack-{}", channel),
+            NotificationHandle::PagerDuty(service) => write!(f, "@pagerduty-{}", service),
+            NotificationHandle::Opsgenie(service) => write!(f, "@opsgenie-{}", service),
+            NotificationHandle::Teams(channel) => write!(f, "@teams-{}", channel),
+            NotificationHandle::Webhook(name) => write!(f, "@webhook-{}", name),
+            NotificationHandle::Email(address) => write!(f, "@{}", address),
+            NotificationHandle::Other(handle) => write!(f, "@{}", handle),
+        }
+    }
+}
+
+/// A piece of a monitor message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessagePart {
+    /// Literal text.
+    Text(String),
+    /// A template variable, e.g. `value` for `{{value}}`.
+    Variable(String),
+    /// A notification handle.
+    Handle(NotificationHandle),
+    /// A conditional block. `inverted` renders `{{^...}}`, shown when the condition does not hold.
+    Block {
+        condition: MessageCondition,
+        inverted: bool,
+        message: MonitorMessage,
+    },
+}
+
+/// Builder of monitor notification messages, which always renders balanced blocks,
+/// e.g. `MonitorMessage::default().text("CPU is ").variable("value").block(MessageCondition::IsAlert, MonitorMessage::default().handle(NotificationHandle::Slack("ops".into())))`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MonitorMessage {
+    pub parts: Vec<MessagePart>,
+}
+
+impl MonitorMessage {
+    pub fn text(mut self, text: &str) -> MonitorMessage {
+        self.parts.push(MessagePart::Text(text.into()));
+        self
+    }
+    /// Literal text followed by a newline.
+    pub fn line(self, text: &str) -> MonitorMessage {
+        self.text(&format!("{}\n", text))
+    }
+    pub fn variable(mut self, variable: &str) -> MonitorMessage {
+        self.parts.push(MessagePart::Variable(variable.into()));
+        self
+    }
+    pub fn handle(mut self, handle: NotificationHandle) -> MonitorMessage {
+        self.parts.push(MessagePart::Handle(handle));
+        self
+    }
+    /// Render `message` only when `condition` holds.
+    pub fn block(mut self, condition: MessageCondition, message: MonitorMessage) -> MonitorMessage {
+        self.parts.push(MessagePart::Block { condition, inverted: false, message });
+        self
+    }
+    /// Render `message` only when `condition` does not hold.
+    pub fn unless(mut self, condition: MessageCondition, message: MonitorMessage) -> MonitorMessage {
+        self.parts.push(MessagePart::Block { condition, inverted: true, message });
+        self
+    }
+
+    /// Check the rendered message with [`MonitorMessage::validate`].
+    pub fn validate_for(&self, monitor_type: &MonitorType) -> Result<(), MonitorValidationErrors> {
+        MonitorMessage::validate(&self.to_string(), monitor_type)
+    }
+
+    /// Check a message for unbalanced blocks, unknown block names, empty notification handles and template variables
+    /// not available for the monitor type.
+    pub fn validate(message: &str, monitor_type: &MonitorType) -> Result<(), MonitorValidationErrors> {
+        let mut messages: Vec<String> = vec![];
+        let mut open: Vec<&str> = vec![];
+        let mut rest = message;
+        while let Some(start) = rest.find("{{") {
+            let tag_start = &rest[start + 2..];
+            let (tag, after) = match tag_start.strip_prefix('{') {
+                Some(tag_start) => match tag_start.find("}}}") {
+                    Some(end) => (&tag_start[..end], &tag_start[end + 3..]),
+                    None => {
+                        messages.push("Unclosed `{{{`".into());
+                        break;
+                    }
+                },
+                None => match tag_start.find("}}") {
+                    Some(end) => (&tag_start[..end], &tag_start[end + 2..]),
+                    None => {
+                        messages.push("Unclosed `{{`".into());
+                        break;
+                    }
+                },
+            };
+            rest = after;
+
+            let tag = tag.trim();
+            let name = tag.split_whitespace().next().unwrap_or("");
+            if let Some(block) = name.strip_prefix('#').or_else(|| name.strip_prefix('^')) {
+                if !CONDITIONS.contains(&block) {
+                    messages.push(format!("Unknown block `{{{{{}}}}}`", tag));
+                }
+                open.push(block);
+            } else if let Some(block) = name.strip_prefix('/') {
+                match open.pop() {
+                    Some(opened) if opened == block => {}
+                    Some(opened) => messages.push(format!("`{{{{/{}}}}}` closes `{{{{#{}}}}}`", block, opened)),
+                    None => messages.push(format!("`{{{{/{}}}}}` has no opening block", block)),
+                }
+            } else if name == "else" {
+                if open.is_empty() {
+                    messages.push("`{{else}}` outside of a block".into());
+                }
+            } else if name == "eval" || name.starts_with('!') {
+                // Evaluated expressions and comments are not checked.
+            } else if !variable_available(name, monitor_type) {
+                messages.push(format!("Unknown template variable `{{{{{}}}}}` for {} monitors", name, type_name(monitor_type)));
+            }
+        }
+        for block in open.iter().rev() {
+            messages.push(format!("Unclosed block `{{{{#{}}}}}`", block));
+        }
+
+        for word in message.split(|c: char| c.is_whitespace() || c == '{' || c == '}') {
+            if let Some(handle) = word.strip_prefix('@') {
+                if ["slack-", "pagerduty-", "opsgenie-", "teams-", "webhook-"].contains(&handle) || handle.is_empty() {
+                    messages.push(format!("Empty notification handle `{}`", word));
+                }
+            }
+        }
+
+        match messages.is_empty() {
+            true => Ok(()),
+            false => Err(MonitorValidationErrors {
+                errors: messages
+                    .into_iter()
+                    .map(|message| MonitorValidationError {
+                        field: Some("message".into()),
+                        message,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+/// Variables available to every monitor type.
+const COMMON_VARIABLES: &[&str] = &[
+    "value",
+    "threshold",
+    "warn_threshold",
+    "ok_threshold",
+    "comparator",
+    "first_triggered_at",
+    "first_triggered_at_epoch",
+    "last_triggered_at",
+    "last_triggered_at_epoch",
+    "triggered_duration_sec",
+];
+
+fn variable_available(variable: &str, monitor_type: &MonitorType) -> bool {
+    if COMMON_VARIABLES.contains(&variable) {
+        return true;
+    }
+    // Group variables, e.g. `host.name` or `service.name`, and host attributes.
+    if variable.ends_with(".name") || variable.starts_with("host.") {
+        return true;
+    }
+    let prefixes: &[&str] = match monitor_type {
+        MonitorType::LogAlert => &["log."],
+        MonitorType::TraceAnalyticsAlert => &["span.", "trace."],
+        MonitorType::RumAlert => &["rum."],
+        MonitorType::EventAlert | MonitorType::EventV2Alert => &["event."],
+        MonitorType::AuditAlert => &["audit."],
+        MonitorType::SyntheticsAlert => &["synthetics."],
+        MonitorType::CiPipelinesAlert => &["cipipeline."],
+        MonitorType::ServiceCheck => return variable == "check_message",
+        _ => &[],
+    };
+    prefixes.iter().any(|prefix| variable.starts_with(prefix))
+}
+
+fn type_name(monitor_type: &MonitorType) -> String {
+    match serde_json::to_value(monitor_type) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => format!("{:?}", monitor_type),
+    }
+}
+
+impl CreateMonitorRequest {
+    /// Set the message from a message builder.
+    pub fn monitor_message(mut self, message: &MonitorMessage) -> CreateMonitorRequest {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+impl fmt::Display for MessagePart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessagePart::Text(text) => write!(f, "{}", text),
+            MessagePart::Variable(variable) => write!(f, "{{{{{}}}}}", variable),
+            MessagePart::Handle(handle) => write!(f, "{}", handle),
+            MessagePart::Block { condition, inverted, message } => {
+                let open = match inverted {
+                    true => '^',
+                    false => '#',
+                };
+                write!(f, "{{{{{}{}}}}}{}{{{{/{}}}}}", open, condition, message, condition.name())
+            }
+        }
+    }
+}
+
+impl fmt::Display for MonitorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in &self.parts {
+            write!(f, "{}", part)?;
+        }
+        Ok(())
+    }
+}
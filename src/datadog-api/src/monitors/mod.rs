@@ -1,10 +1,27 @@
 //! Monitors allow you to watch a metric or check that you care about and notifies your team when a defined threshold has exceeded.
+mod composite;
+mod event_query;
+mod lint;
+mod message;
+mod mute;
+mod query;
+mod reconcile;
+mod validate;
+mod watch;
+
+pub use composite::*;
+pub use event_query::*;
+pub use lint::*;
+pub use message::*;
+pub use mute::*;
+pub use query::*;
+pub use reconcile::*;
+pub use validate::*;
+pub use watch::*;
 use crate::client::*;
 use serde::{Deserialize, Serialize};
-use async_gen::Return;
-use std::{future::Future, pin::pin};
 use std::collections::HashMap;
-use async_gen::{self, AsyncGen, AsyncIter};
+use async_gen::{self, AsyncIter};
 use futures_core::Stream;
 
 /// Search and filter your monitors details.
@@ -65,6 +82,24 @@ impl MonitorsSearchRequest {
             .await
     }
 
+    /// Fetch every page of the search, returning the first error instead of panicking like [`MonitorsSearchRequest::iter`].
+    pub async fn send_all(&self, client: &Client) -> DatadogResult<Vec<Monitor>> {
+        let mut monitors: Vec<Monitor> = vec![];
+        let mut page = self.page.unwrap_or(0);
+        loop {
+            let request = MonitorsSearchRequest {
+                page: Some(page),
+                ..self.clone()
+            };
+            let result = request.send(client).await?;
+            monitors.extend(result.monitors);
+            if result.metadata.page + 1 >= result.metadata.page_count {
+                return Ok(monitors);
+            }
+            page += 1;
+        }
+    }
+
     pub fn iter<'a>(&'a self, client: &'a Client) -> impl Stream<Item = Monitor> + 'a {
         let iter = AsyncIter::from(async_gen::gen! {
             let mut page = self.page.unwrap_or(0);
@@ -84,7 +119,7 @@ impl MonitorsSearchRequest {
                 if result.metadata.page >= result.metadata.page_count {
                     return;
                 }
-                page = page + 1;
+                page += 1;
             }
         });
         iter
@@ -173,6 +208,7 @@ pub enum Status {
     Unknown,
     Warn
 }
+#[allow(clippy::derivable_impls)]
 impl Default for Status {
   fn default() -> Status {
     Status::Unknown
@@ -201,7 +237,7 @@ pub enum MonitorType {
     ServiceCheck,
     #[serde(rename="synthetics alert")]
     SyntheticsAlert,
-    #[serde(rename="trace-analytics aleert")]
+    #[serde(rename="trace-analytics alert")]
     TraceAnalyticsAlert,
     #[serde(rename="slo alert")]
     SloAlert,
@@ -212,6 +248,7 @@ pub enum MonitorType {
     #[serde(rename="ci-pipelines alert")]
     CiPipelinesAlert,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for MonitorType {
   fn default() -> MonitorType {
     MonitorType::LogAlert
@@ -262,72 +299,366 @@ pub struct MonitorsSearchResponse {
     pub monitors: Vec<Monitor>,
 }
 
+/// Search and filter your monitor groups details.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorGroupsSearchRequest {
+    /// After entering a search query in your [Manage Monitor page](https://app.datadoghq.com/monitors/manage) use the query parameter value in the URL of the page as value for this parameter. Consult the dedicated [manage monitor documentation](https://docs.datadoghq.com/monitors/manage/#find-the-monitors) page to learn more.
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Page to start paginating from.
+    pub page: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Number of monitors to return per page.
+    pub per_page: Option<u64>,
+    /// String for sort order, composed of field and sort order separate by a comma, e.g. `name,asc`. Supported sort directions: `asc`, `desc`.
+    /// Supported fields: `name`, `status`, `tags`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+impl MonitorGroupsSearchRequest {
+    pub fn query(mut self, query: &str) -> MonitorGroupsSearchRequest {
+        self.query = query.into();
+        self
+    }
+    pub fn page(mut self, page: u64) -> MonitorGroupsSearchRequest {
+        self.page = Some(page);
+        self
+    }
+    pub fn per_page(mut self, per_page: u64) -> MonitorGroupsSearchRequest {
+        self.per_page = Some(per_page);
+        self
+    }
+    pub fn sort(mut self, sort: &str) -> MonitorGroupsSearchRequest {
+        self.sort = Some(sort.into());
+        self
+    }
+}
+
+impl MonitorGroupsSearchRequest {
+    /// The path and percent-encoded query string of the search.
+    pub fn path_and_query(&self) -> String {
+        let mut queries: Vec<(&str, String)> = vec![("query", self.query.clone())];
+        if let Some(page) = &self.page {
+            queries.push(("page", page.to_string()));
+        }
+        if let Some(per_page) = &self.per_page {
+            queries.push(("per_page", per_page.to_string()));
+        }
+        if let Some(sort) = &self.sort {
+            queries.push(("sort", sort.to_string()));
+        }
+        path_with_query("/api/v1/monitor/groups/search", &queries)
+    }
+
+    pub async fn send(&self, client: &Client) -> DatadogResult<MonitorGroupsSearchResponse> {
+        let path_and_query = self.path_and_query();
+
+        client
+            .get::<MonitorGroupsSearchRequest, MonitorGroupsSearchResponse>(&path_and_query)
+            .await
+    }
+
+    pub fn iter<'a>(&'a self, client: &'a Client) -> impl Stream<Item = MonitorGroup> + 'a {
+        AsyncIter::from(async_gen::gen! {
+            let mut page = self.page.unwrap_or(0);
+            loop {
+                let request = MonitorGroupsSearchRequest {
+                    query: self.query.clone(),
+                    page: Some(page),
+                    per_page: self.per_page,
+                    sort: self.sort.clone(),
+                };
+                let result = request.send(client).await.expect("Failed to call .send()");
+                // Iterate over all results
+                for item in result.groups {
+                    yield item;
+                }
+                // Stop iterating when we hit the last page
+                if result.metadata.page + 1 >= result.metadata.page_count {
+                    return;
+                }
+                page += 1;
+            }
+        })
+    }
+}
+
+/// A monitor group, i.e. one of the groups a monitor is broken down on.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorGroup {
+    /// The name of the group.
+    pub group: String,
+    /// The list of tags of the monitor group.
+    pub group_tags: Vec<String>,
+    /// Latest timestamp the monitor group was in NO_DATA state.
+    pub last_nodata_ts: Option<u64>,
+    /// Latest timestamp the monitor group triggered.
+    pub last_triggered_ts: Option<u64>,
+    /// The ID of the monitor.
+    pub monitor_id: u64,
+    /// The name of the monitor.
+    pub monitor_name: String,
+    /// The different states your monitor can be in. Allowed enum values: `Alert`, `Ignored`, `No Data`, `OK`, `Skipped`, `Unknown`, `Warn`
+    pub status: Status,
+}
+
+/// The response of a monitor group search.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorGroupsSearchResponse {
+    /// The counts of monitor groups per different criteria.
+    pub counts: SearchFacetCounts,
+    /// The list of found monitor groups.
+    pub groups: Vec<MonitorGroup>,
+    /// Metadata about the response.
+    pub metadata: Metadata,
+}
+
+/// List of options associated with your monitor.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MonitorOptions {
     /// Type of aggregation performed in the monitor query.
-    pub aggregation: Aggregation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation: Option<Aggregation>,
     /// DEPRECATED: IDs of the device the Synthetics monitor is running on.
-    pub device_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_ids: Option<Vec<String>>,
     /// Whether or not to send a log sample when the log monitor triggers.
-    pub enable_logs_sample: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_logs_sample: Option<bool>,
+    /// Whether or not to send a list of samples when the monitor triggers. This is only used by CI Test and Pipeline monitors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_samples: Option<bool>,
     /// We recommend using the is_renotify, block in the original message instead. A message to include with a re-notification. Supports the @username notification we allow elsewhere. Not applicable if renotify_interval is None.
-    pub escalation_message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalation_message: Option<String>,
     /// Time (in seconds) to delay evaluation, as a non-negative integer. For example, if the value is set to 300 (5min), the timeframe is set to last_5m and the time is 7:00, the monitor evaluates data from 6:50 to 6:55. This is useful for AWS CloudWatch and other backfilled metrics to ensure the monitor always has data during evaluation.
-    pub evaluation_delay: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluation_delay: Option<u64>,
+    /// The time span after which groups with missing data are dropped from the monitor state. The minimum value is one hour, and the maximum value is 72 hours. Example values are: "60m", "1h", and "2d". This option is only available for APM Trace Analytics, Audit Trail, CI, Error Tracking, Event, Logs, and RUM monitors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_retention_duration: Option<String>,
     /// Whether the log alert monitor triggers a single alert or multiple alerts when any group breaches a threshold.
-    pub groupby_simple_monitor: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groupby_simple_monitor: Option<bool>,
     /// A Boolean indicating whether notifications from this monitor automatically inserts its triggering tags into the title.
-    pub include_tags: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
     /// Whether or not the monitor is locked (only editable by creator and admins).
-    pub locked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
     /// How long the test should be in failure before alerting (integer, number of seconds, max 7200).
-    pub min_failure_duration: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_failure_duration: Option<u64>,
     /// The minimum number of locations in failure at the same time during at least one moment in the min_failure_duration period (min_location_failed and min_failure_duration are part of the advanced alerting rules - integer, >= 1).
-    pub min_location_failed: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_location_failed: Option<u64>,
     /// Time (in seconds) to skip evaluations for new groups.
     /// For example, this option can be used to skip evaluations for new hosts while they initialize.
     /// Must be a non negative integer.
-    pub new_group_delay: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_group_delay: Option<u64>,
     /// DEPRECATED: Time (in seconds) to allow a host to boot and applications to fully start before starting the evaluation of monitor results. Should be a non negative integer.
     /// Use new_group_delay instead.
-    pub new_host_delay: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_host_delay: Option<u64>,
     /// The number of minutes before a monitor notifies after data stops reporting. Datadog recommends at least 2x the monitor timeframe for query alerts or 2 minutes for service checks. If omitted, 2x the evaluation timeframe is used for query alerts, and 24 hours is used for service checks.
-    pub no_data_timeframe: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_data_timeframe: Option<u64>,
+    /// Toggles the display of additional content sent in the monitor notification. Allowed enum values: `show_all`, `hide_query`, `hide_handles`, `hide_all`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_preset_name: Option<NotificationPresetName>,
     /// A Boolean indicating whether tagged users is notified on changes to this monitor.
-    pub notify_audit: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_audit: Option<bool>,
+    /// Controls what granularity a monitor alerts on. Only available for monitors with groupings. For instance, a monitor grouped by `cluster`, `namespace`, and `pod` can be configured to only notify on each new `cluster` violating the alert conditions by setting `notify_by` to `["cluster"]`. Tags mentioned in `notify_by` must be a subset of the grouping tags in the query. For example, a query grouped by `cluster` and `namespace` cannot notify on `region`. Setting `notify_by` to `["*"]` configures the monitor to notify as a simple-alert.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_by: Option<Vec<String>>,
     /// A Boolean indicating whether this monitor notifies when data stops reporting.
-    pub notify_no_data: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_no_data: Option<bool>,
+    /// Controls how groups or monitors are treated if an evaluation does not return any data points. The default option results in different behavior depending on the monitor query type. Allowed enum values: `default`, `show_no_data`, `show_and_notify_no_data`, `resolve`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_missing_data: Option<OnMissingData>,
     /// The number of minutes after the last notification before a monitor re-notifies on the current status. It only re-notifies if it’s not resolved.
-    pub renotify_interval: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renotify_interval: Option<u64>,
     /// The number of times re-notification messages should be sent on the current status at the provided re-notification interval.
-    pub renotify_occurrences: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renotify_occurrences: Option<u64>,
     /// The types of monitor statuses for which re-notification messages are sent.
-    pub renotify_statuses: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renotify_statuses: Option<Vec<String>>,
     /// A Boolean indicating whether this monitor needs a full window of data before it’s evaluated. We highly recommend you set this to false for sparse metrics, otherwise some evaluations are skipped. Default is false.
-    pub require_full_window: bool,
-    // DEPRECATED: Information about the downtime applied to the monitor.
-    // pub silenced: object,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_full_window: Option<bool>,
+    /// Configuration options for scheduling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduling_options: Option<SchedulingOptions>,
+    /// DEPRECATED: Information about the downtime applied to the monitor. Use [`MuteMonitorRequest`] or downtimes to change it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub silenced: Option<Silenced>,
     /// DEPRECATED: ID of the corresponding Synthetic check.
-    pub synthetics_check_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synthetics_check_id: Option<String>,
     /// Alerting time window options.
-    pub threshold_windows: ThresholdWindows,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_windows: Option<ThresholdWindows>,
     /// List of the different monitor threshold available.
-    pub thresholds: Thresholds,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thresholds: Option<Thresholds>,
     /// The number of hours of the monitor not reporting data before it automatically resolves from a triggered state.
-    pub timeout_h: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_h: Option<u64>,
+    /// List of requests that can be used in the monitor query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Vec<MonitorFormulaAndFunctionQuery>>,
+}
+impl MonitorOptions {
+    pub fn aggregation(mut self, aggregation: Aggregation) -> MonitorOptions {
+        self.aggregation = Some(aggregation);
+        self
+    }
+    pub fn enable_logs_sample(mut self, enable_logs_sample: bool) -> MonitorOptions {
+        self.enable_logs_sample = Some(enable_logs_sample);
+        self
+    }
+    pub fn escalation_message(mut self, escalation_message: &str) -> MonitorOptions {
+        self.escalation_message = Some(escalation_message.into());
+        self
+    }
+    pub fn evaluation_delay(mut self, evaluation_delay: u64) -> MonitorOptions {
+        self.evaluation_delay = Some(evaluation_delay);
+        self
+    }
+    pub fn group_retention_duration(mut self, group_retention_duration: &str) -> MonitorOptions {
+        self.group_retention_duration = Some(group_retention_duration.into());
+        self
+    }
+    pub fn groupby_simple_monitor(mut self, groupby_simple_monitor: bool) -> MonitorOptions {
+        self.groupby_simple_monitor = Some(groupby_simple_monitor);
+        self
+    }
+    pub fn include_tags(mut self, include_tags: bool) -> MonitorOptions {
+        self.include_tags = Some(include_tags);
+        self
+    }
+    pub fn locked(mut self, locked: bool) -> MonitorOptions {
+        self.locked = Some(locked);
+        self
+    }
+    pub fn new_group_delay(mut self, new_group_delay: u64) -> MonitorOptions {
+        self.new_group_delay = Some(new_group_delay);
+        self
+    }
+    pub fn no_data_timeframe(mut self, no_data_timeframe: u64) -> MonitorOptions {
+        self.no_data_timeframe = Some(no_data_timeframe);
+        self
+    }
+    pub fn notification_preset_name(mut self, notification_preset_name: NotificationPresetName) -> MonitorOptions {
+        self.notification_preset_name = Some(notification_preset_name);
+        self
+    }
+    pub fn notify_audit(mut self, notify_audit: bool) -> MonitorOptions {
+        self.notify_audit = Some(notify_audit);
+        self
+    }
+    pub fn notify_by(mut self, notify_by: Vec<String>) -> MonitorOptions {
+        self.notify_by = Some(notify_by);
+        self
+    }
+    pub fn notify_no_data(mut self, notify_no_data: bool) -> MonitorOptions {
+        self.notify_no_data = Some(notify_no_data);
+        self
+    }
+    pub fn on_missing_data(mut self, on_missing_data: OnMissingData) -> MonitorOptions {
+        self.on_missing_data = Some(on_missing_data);
+        self
+    }
+    pub fn renotify_interval(mut self, renotify_interval: u64) -> MonitorOptions {
+        self.renotify_interval = Some(renotify_interval);
+        self
+    }
+    pub fn renotify_occurrences(mut self, renotify_occurrences: u64) -> MonitorOptions {
+        self.renotify_occurrences = Some(renotify_occurrences);
+        self
+    }
+    pub fn renotify_statuses(mut self, renotify_statuses: Vec<String>) -> MonitorOptions {
+        self.renotify_statuses = Some(renotify_statuses);
+        self
+    }
+    pub fn require_full_window(mut self, require_full_window: bool) -> MonitorOptions {
+        self.require_full_window = Some(require_full_window);
+        self
+    }
+    pub fn scheduling_options(mut self, scheduling_options: SchedulingOptions) -> MonitorOptions {
+        self.scheduling_options = Some(scheduling_options);
+        self
+    }
+    pub fn silenced(mut self, silenced: Silenced) -> MonitorOptions {
+        self.silenced = Some(silenced);
+        self
+    }
+    pub fn threshold_windows(mut self, threshold_windows: ThresholdWindows) -> MonitorOptions {
+        self.threshold_windows = Some(threshold_windows);
+        self
+    }
+    pub fn thresholds(mut self, thresholds: Thresholds) -> MonitorOptions {
+        self.thresholds = Some(thresholds);
+        self
+    }
+    pub fn timeout_h(mut self, timeout_h: u64) -> MonitorOptions {
+        self.timeout_h = Some(timeout_h);
+        self
+    }
+    pub fn variables(mut self, variables: Vec<MonitorFormulaAndFunctionQuery>) -> MonitorOptions {
+        self.variables = Some(variables);
+        self
+    }
 }
 
-
+/// Type of aggregation performed in the monitor query.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Aggregation {
     /// Group to break down the monitor on.
-    pub group_by: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
     /// Metric name used in the monitor.
-    pub metric: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
     /// Metric type used in the monitor.
-    pub _type: String,
+    #[serde(rename="type", skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+}
+
+/// A threshold value, kept as an integer or a float as Datadog sends it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThresholdValue {
+    Integer(i64),
+    Float(f64),
+}
+impl ThresholdValue {
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            ThresholdValue::Integer(value) => *value as f64,
+            ThresholdValue::Float(value) => *value,
+        }
+    }
+}
+impl From<i64> for ThresholdValue {
+    fn from(value: i64) -> ThresholdValue {
+        ThresholdValue::Integer(value)
+    }
+}
+impl From<f64> for ThresholdValue {
+    fn from(value: f64) -> ThresholdValue {
+        ThresholdValue::Float(value)
+    }
 }
 
 /// The different monitor threshold available.
@@ -335,27 +666,257 @@ pub struct Aggregation {
 #[serde(default)]
 pub struct Thresholds {
     /// The monitor `CRITICAL` threshold.
-    pub critical: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub critical: Option<ThresholdValue>,
     /// The monitor CRITICAL recovery threshold.
-    pub critical_recovery: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub critical_recovery: Option<ThresholdValue>,
     /// The monitor OK threshold.
-    pub ok: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok: Option<ThresholdValue>,
     /// The monitor UNKNOWN threshold.
-    pub unknown: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unknown: Option<ThresholdValue>,
     /// The monitor WARNING threshold.
-    pub warning: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<ThresholdValue>,
     /// The monitor WARNING recovery threshold.
-    pub warning_recovery: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_recovery: Option<ThresholdValue>,
+}
+impl Thresholds {
+    pub fn critical<T: Into<ThresholdValue>>(mut self, critical: T) -> Thresholds {
+        self.critical = Some(critical.into());
+        self
+    }
+    pub fn critical_recovery<T: Into<ThresholdValue>>(mut self, critical_recovery: T) -> Thresholds {
+        self.critical_recovery = Some(critical_recovery.into());
+        self
+    }
+    pub fn ok<T: Into<ThresholdValue>>(mut self, ok: T) -> Thresholds {
+        self.ok = Some(ok.into());
+        self
+    }
+    pub fn unknown<T: Into<ThresholdValue>>(mut self, unknown: T) -> Thresholds {
+        self.unknown = Some(unknown.into());
+        self
+    }
+    pub fn warning<T: Into<ThresholdValue>>(mut self, warning: T) -> Thresholds {
+        self.warning = Some(warning.into());
+        self
+    }
+    pub fn warning_recovery<T: Into<ThresholdValue>>(mut self, warning_recovery: T) -> Thresholds {
+        self.warning_recovery = Some(warning_recovery.into());
+        self
+    }
 }
 
+/// Muted scopes of a monitor, from the scope (`*` for the whole monitor, or a group such as `host:web-1`) to the POSIX timestamp
+/// the mute ends, `None` when muted until unmuted.
+pub type Silenced = HashMap<String, Option<i64>>;
+
 /// Alerting time window options.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ThresholdWindows {
     /// Describes how long an anomalous metric must be normal before the alert recovers.
-    pub recovery_window: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_window: Option<String>,
     /// Describes how long a metric must be anomalous before an alert triggers.
-    pub trigger_window: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_window: Option<String>,
+}
+impl ThresholdWindows {
+    pub fn recovery_window(mut self, recovery_window: &str) -> ThresholdWindows {
+        self.recovery_window = Some(recovery_window.into());
+        self
+    }
+    pub fn trigger_window(mut self, trigger_window: &str) -> ThresholdWindows {
+        self.trigger_window = Some(trigger_window.into());
+        self
+    }
+}
+
+/// Toggles the display of additional content sent in the monitor notification. Allowed enum values: `show_all`, `hide_query`, `hide_handles`, `hide_all`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotificationPresetName {
+    #[serde(rename="show_all")]
+    ShowAll,
+    #[serde(rename="hide_query")]
+    HideQuery,
+    #[serde(rename="hide_handles")]
+    HideHandles,
+    #[serde(rename="hide_all")]
+    HideAll,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for NotificationPresetName {
+  fn default() -> NotificationPresetName {
+    NotificationPresetName::ShowAll
+  }
+}
+
+/// Controls how groups or monitors are treated if an evaluation does not return any data points. Allowed enum values: `default`, `show_no_data`, `show_and_notify_no_data`, `resolve`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OnMissingData {
+    #[serde(rename="default")]
+    Default,
+    #[serde(rename="show_no_data")]
+    ShowNoData,
+    #[serde(rename="show_and_notify_no_data")]
+    ShowAndNotifyNoData,
+    #[serde(rename="resolve")]
+    Resolve,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for OnMissingData {
+  fn default() -> OnMissingData {
+    OnMissingData::Default
+  }
+}
+
+/// Configuration options for scheduling.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchedulingOptions {
+    /// Configuration options for the custom schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_schedule: Option<CustomSchedule>,
+    /// Configuration options for the evaluation window. If `hour_starts` is set, no other fields may be set. Otherwise, `day_starts` and `month_starts` must be set together.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluation_window: Option<EvaluationWindow>,
+}
+
+/// Configuration options for the custom schedule.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomSchedule {
+    /// Array of custom schedule recurrences.
+    pub recurrences: Vec<CustomScheduleRecurrence>,
+}
+
+/// Configuration for a recurrence of the custom schedule.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomScheduleRecurrence {
+    /// Defines the recurrence rule (RRULE) for a given schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrule: Option<String>,
+    /// Defines the start date and time of the recurring schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    /// Defines the timezone the schedule runs on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// Configuration options for the evaluation window.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvaluationWindow {
+    /// The time of the day at which a one day cumulative evaluation window starts. Must be defined in UTC time in `HH:mm` format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_starts: Option<String>,
+    /// The minute of the hour at which a one hour cumulative evaluation window starts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hour_starts: Option<u8>,
+    /// The day of the month at which a one month cumulative evaluation window starts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month_starts: Option<u8>,
+}
+
+/// A formula and functions query used as a variable of a formula monitor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MonitorFormulaAndFunctionQuery {
+    /// A formula and functions cost query.
+    Cost(MonitorFormulaAndFunctionCostQuery),
+    /// A formula and functions events query.
+    Events(MonitorFormulaAndFunctionEventQuery),
+}
+
+/// A formula and functions cost query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorFormulaAndFunctionCostQuery {
+    /// Aggregation methods for metric queries. Allowed enum values: `avg`, `sum`, `max`, `min`, `last`, `area`, `l2norm`, `percentile`, `stddev`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregator: Option<String>,
+    /// Data source for cost queries. Allowed enum values: `metrics`, `cloud_cost`, `datadog_usage`
+    pub data_source: String,
+    /// Name of the query for use in formulas.
+    pub name: String,
+    /// The monitor query.
+    pub query: String,
+}
+
+/// A formula and functions events query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorFormulaAndFunctionEventQuery {
+    /// Compute options.
+    pub compute: MonitorFormulaAndFunctionEventQueryCompute,
+    /// Data source for event platform-based queries. Allowed enum values: `rum`, `ci_pipelines`, `ci_tests`, `audit`, `events`, `logs`, `spans`, `database_queries`, `network`
+    pub data_source: String,
+    /// Group by options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<MonitorFormulaAndFunctionEventQueryGroupBy>>,
+    /// An array of index names to query in the stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<Vec<String>>,
+    /// Name of the query for use in formulas.
+    pub name: String,
+    /// Search options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<MonitorFormulaAndFunctionEventQuerySearch>,
+}
+
+/// Compute options of a formula and functions events query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorFormulaAndFunctionEventQueryCompute {
+    /// Aggregation methods for event platform queries. Allowed enum values: `count`, `cardinality`, `median`, `pc75`, `pc90`, `pc95`, `pc98`, `pc99`, `sum`, `min`, `max`, `avg`
+    pub aggregation: String,
+    /// A time interval in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u64>,
+    /// Measurable attribute to compute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+}
+
+/// Group by options of a formula and functions events query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorFormulaAndFunctionEventQueryGroupBy {
+    /// Event facet.
+    pub facet: String,
+    /// Number of groups to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    /// Options for sorting group by results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<MonitorFormulaAndFunctionEventQueryGroupBySort>,
+}
+
+/// Options for sorting group by results.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorFormulaAndFunctionEventQueryGroupBySort {
+    /// Aggregation methods for event platform queries.
+    pub aggregation: String,
+    /// Metric used for sorting group by results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    /// Direction of sort. Allowed enum values: `asc`, `desc`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+/// Search options of a formula and functions events query.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorFormulaAndFunctionEventQuerySearch {
+    /// Events search string.
+    pub query: String,
 }
 
 /// Create a monitor request body.
@@ -417,7 +978,7 @@ impl CreateMonitorRequest {
         self
     }
     pub fn _type(mut self, _type: MonitorType) -> CreateMonitorRequest {
-        self._type = _type.into();
+        self._type = _type;
         self
     }
 }
@@ -427,7 +988,7 @@ impl CreateMonitorRequest {
         let path_and_query = "/api/v1/monitor";
 
         client
-            .post::<CreateMonitorRequest, CreateMonitorResponse>(&path_and_query, &self)
+            .post::<CreateMonitorRequest, CreateMonitorResponse>(path_and_query, self)
             .await
     }
 }
@@ -445,6 +1006,9 @@ pub struct CreateMonitorResponse {
     pub deleted: Option<String>,
     /// ID of the monitor.
     pub id: u64,
+    /// A list of active downtimes that match this monitor. Only returned when requested with `with_downtimes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_downtimes: Option<Vec<MatchingDowntime>>,
     /// A message to include with notifications for this monitor.
     pub message: String,
     /// Last timestamp when the monitor was edited.
@@ -475,6 +1039,20 @@ pub struct CreateMonitorResponse {
     pub _type: MonitorType,
 }
 
+/// Data about an active downtime that matches a monitor.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchingDowntime {
+    /// POSIX timestamp to end the downtime.
+    pub end: Option<u64>,
+    /// The downtime ID.
+    pub id: u64,
+    /// The scope(s) to which the downtime applies.
+    pub scope: Vec<String>,
+    /// POSIX timestamp to start the downtime.
+    pub start: Option<u64>,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct State {
@@ -567,7 +1145,7 @@ impl EditMonitorRequest {
         self
     }
     pub fn _type(mut self, _type: MonitorType) -> EditMonitorRequest {
-        self._type = _type.into();
+        self._type = _type;
         self
     }
 }
@@ -577,15 +1155,252 @@ impl EditMonitorRequest {
         let path_and_query = format!("/api/v1/monitor/{}", &self.monitor_id);
 
         client
-            .put::<EditMonitorRequest, EditMonitorResponse>(&path_and_query, &self)
+            .put::<EditMonitorRequest, EditMonitorResponse>(&path_and_query, self)
             .await
     }
 }
 
+impl From<CreateMonitorResponse> for EditMonitorRequest {
+    /// Start an edit from a fetched monitor, keeping every field that was set.
+    fn from(monitor: CreateMonitorResponse) -> EditMonitorRequest {
+        EditMonitorRequest {
+            monitor_id: monitor.id,
+            message: Some(monitor.message),
+            name: Some(monitor.name),
+            options: monitor.options,
+            priority: monitor.priority,
+            query: monitor.query,
+            restricted_roles: monitor.restricted_roles,
+            tags: Some(monitor.tags),
+            _type: monitor._type,
+        }
+    }
+}
+
+impl From<CreateMonitorResponse> for CreateMonitorRequest {
+    /// Start a new definition from a fetched monitor, keeping every field that was set.
+    fn from(monitor: CreateMonitorResponse) -> CreateMonitorRequest {
+        CreateMonitorRequest {
+            message: Some(monitor.message),
+            name: Some(monitor.name),
+            options: monitor.options,
+            priority: monitor.priority,
+            query: monitor.query,
+            restricted_roles: monitor.restricted_roles,
+            tags: Some(monitor.tags),
+            _type: monitor._type,
+        }
+    }
+}
+
 /// Object describing a monitor
 pub type EditMonitorResponse = CreateMonitorResponse;
 
 
+/// Get details about the specified monitor from your organization.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GetMonitorRequest {
+    // Path
+    /// The ID of the monitor.
+    #[serde(skip_serializing)]
+    pub monitor_id: u64,
+    // Query
+    /// When specified, shows additional information about the group states. Choose one or more from `all`, `alert`, `warn`, and `no data`.
+    #[serde(skip_serializing)]
+    pub group_states: Option<String>,
+    /// If this argument is set to true, then the returned data includes all current active downtimes for the monitor.
+    #[serde(skip_serializing)]
+    pub with_downtimes: Option<bool>,
+}
+impl GetMonitorRequest {
+    pub fn monitor_id(mut self, monitor_id: u64) -> GetMonitorRequest {
+        self.monitor_id = monitor_id;
+        self
+    }
+    pub fn group_states(mut self, group_states: &str) -> GetMonitorRequest {
+        self.group_states = Some(group_states.into());
+        self
+    }
+    pub fn with_downtimes(mut self, with_downtimes: bool) -> GetMonitorRequest {
+        self.with_downtimes = Some(with_downtimes);
+        self
+    }
+}
+
+impl GetMonitorRequest {
+    pub async fn send(&self, client: &Client) -> DatadogResult<GetMonitorResponse> {
+        let mut queries: Vec<(&str, String)> = vec![];
+        if let Some(group_states) = &self.group_states {
+            queries.push(("group_states", group_states.to_string()));
+        }
+        if let Some(with_downtimes) = &self.with_downtimes {
+            queries.push(("with_downtimes", with_downtimes.to_string()));
+        }
+        let path = format!("/api/v1/monitor/{}", &self.monitor_id);
+        let path_and_query = path_with_query(&path, &queries);
+
+        client
+            .get::<GetMonitorRequest, GetMonitorResponse>(&path_and_query)
+            .await
+    }
+}
+
+/// Object describing a monitor
+pub type GetMonitorResponse = CreateMonitorResponse;
+
+
+/// Get details about the specified monitors from your organization.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListMonitorsRequest {
+    // Query
+    /// When specified, shows additional information about the group states. Choose one or more from `all`, `alert`, `warn`, and `no data`.
+    #[serde(skip_serializing)]
+    pub group_states: Option<String>,
+    /// A string to filter monitors by name.
+    #[serde(skip_serializing)]
+    pub name: Option<String>,
+    /// A comma separated list indicating what tags, if any, should be used to filter the list of monitors by scope. For example, `host:host0`.
+    #[serde(skip_serializing)]
+    pub tags: Option<String>,
+    /// A comma separated list indicating what service and/or custom tags, if any, should be used to filter the list of monitors. Tags created in the Datadog UI automatically have the service key prepended. For example, `service:my-app`.
+    #[serde(skip_serializing)]
+    pub monitor_tags: Option<String>,
+    /// If this argument is set to true, then the returned data includes all current active downtimes for each monitor.
+    #[serde(skip_serializing)]
+    pub with_downtimes: Option<bool>,
+    /// Use this parameter for paginating through large sets of monitors. Start with a value of zero, make a request, set the value to the last ID of result set, and then repeat until the response is empty.
+    #[serde(skip_serializing)]
+    pub id_offset: Option<u64>,
+    /// The page to start paginating from. If this argument is not specified, the request returns all monitors without pagination.
+    #[serde(skip_serializing)]
+    pub page: Option<u64>,
+    /// The number of monitors to return per page. If the page argument is not specified, the default behavior returns all monitors without a `page_size` limit. However, if page is specified and `page_size` is not, the argument defaults to 100.
+    #[serde(skip_serializing)]
+    pub page_size: Option<u64>,
+}
+impl ListMonitorsRequest {
+    pub fn group_states(mut self, group_states: &str) -> ListMonitorsRequest {
+        self.group_states = Some(group_states.into());
+        self
+    }
+    pub fn name(mut self, name: &str) -> ListMonitorsRequest {
+        self.name = Some(name.into());
+        self
+    }
+    pub fn tags(mut self, tags: &str) -> ListMonitorsRequest {
+        self.tags = Some(tags.into());
+        self
+    }
+    pub fn monitor_tags(mut self, monitor_tags: &str) -> ListMonitorsRequest {
+        self.monitor_tags = Some(monitor_tags.into());
+        self
+    }
+    pub fn with_downtimes(mut self, with_downtimes: bool) -> ListMonitorsRequest {
+        self.with_downtimes = Some(with_downtimes);
+        self
+    }
+    pub fn id_offset(mut self, id_offset: u64) -> ListMonitorsRequest {
+        self.id_offset = Some(id_offset);
+        self
+    }
+    pub fn page(mut self, page: u64) -> ListMonitorsRequest {
+        self.page = Some(page);
+        self
+    }
+    pub fn page_size(mut self, page_size: u64) -> ListMonitorsRequest {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+impl ListMonitorsRequest {
+    pub async fn send(&self, client: &Client) -> DatadogResult<ListMonitorsResponse> {
+        let mut queries: Vec<(&str, String)> = vec![];
+        if let Some(group_states) = &self.group_states {
+            queries.push(("group_states", group_states.to_string()));
+        }
+        if let Some(name) = &self.name {
+            queries.push(("name", name.to_string()));
+        }
+        if let Some(tags) = &self.tags {
+            queries.push(("tags", tags.to_string()));
+        }
+        if let Some(monitor_tags) = &self.monitor_tags {
+            queries.push(("monitor_tags", monitor_tags.to_string()));
+        }
+        if let Some(with_downtimes) = &self.with_downtimes {
+            queries.push(("with_downtimes", with_downtimes.to_string()));
+        }
+        if let Some(id_offset) = &self.id_offset {
+            queries.push(("id_offset", id_offset.to_string()));
+        }
+        if let Some(page) = &self.page {
+            queries.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = &self.page_size {
+            queries.push(("page_size", page_size.to_string()));
+        }
+        let path = "/api/v1/monitor";
+        let path_and_query = path_with_query(path, &queries);
+
+        client
+            .get::<ListMonitorsRequest, ListMonitorsResponse>(&path_and_query)
+            .await
+    }
+
+    /// Fetch every page of monitors, returning the first error instead of panicking like [`ListMonitorsRequest::iter`].
+    pub async fn send_all(&self, client: &Client) -> DatadogResult<ListMonitorsResponse> {
+        let mut monitors: ListMonitorsResponse = vec![];
+        let mut page = self.page.unwrap_or(0);
+        let page_size = self.page_size.unwrap_or(100);
+        loop {
+            let request = ListMonitorsRequest {
+                page: Some(page),
+                page_size: Some(page_size),
+                ..self.clone()
+            };
+            let result = request.send(client).await?;
+            let count = result.len() as u64;
+            monitors.extend(result);
+            if count < page_size {
+                return Ok(monitors);
+            }
+            page += 1;
+        }
+    }
+
+    pub fn iter<'a>(&'a self, client: &'a Client) -> impl Stream<Item = GetMonitorResponse> + 'a {
+        AsyncIter::from(async_gen::gen! {
+            let mut page = self.page.unwrap_or(0);
+            let page_size = self.page_size.unwrap_or(100);
+            loop {
+                let request = ListMonitorsRequest {
+                    page: Some(page),
+                    page_size: Some(page_size),
+                    ..self.clone()
+                };
+                let result = request.send(client).await.expect("Failed to call .send()");
+                let count = result.len() as u64;
+                // Iterate over all results
+                for item in result {
+                    yield item;
+                }
+                // Stop iterating when we hit the last page
+                if count < page_size {
+                    return;
+                }
+                page += 1;
+            }
+        })
+    }
+}
+
+/// An array of monitor objects.
+pub type ListMonitorsResponse = Vec<GetMonitorResponse>;
+
+
 
 /// Delete the specified monitor
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
@@ -598,6 +1413,9 @@ pub struct DeleteMonitorRequest {
     /// Delete the monitor even if it’s referenced by other resources (e.g. SLO, composite monitor).
     #[serde(skip_serializing)]
     pub force: Option<bool>,
+    /// Check the monitor can be deleted before deleting it, returning [`MonitorValidationErrors`] as the error if it can't. Ignored when `force` is set.
+    #[serde(skip_serializing)]
+    pub check_can_delete: Option<bool>,
 }
 impl DeleteMonitorRequest {
     pub fn monitor_id(mut self, monitor_id: u64) -> DeleteMonitorRequest {
@@ -608,10 +1426,23 @@ impl DeleteMonitorRequest {
         self.force = Some(force);
         self
     }
+    pub fn check_can_delete(mut self, check_can_delete: bool) -> DeleteMonitorRequest {
+        self.check_can_delete = Some(check_can_delete);
+        self
+    }
 }
 
 impl DeleteMonitorRequest {
     pub async fn send(&self, client: &Client) -> DatadogResult<DeleteMonitorResponse> {
+        if self.check_can_delete.unwrap_or(false) && !self.force.unwrap_or(false) {
+            let can_delete = CanDeleteMonitorsRequest::default()
+                .monitor_ids(vec![self.monitor_id])
+                .send(client)
+                .await?;
+            if !can_delete.can_delete(self.monitor_id) {
+                return Err(Box::new(can_delete.validation_errors()));
+            }
+        }
         let mut queries: Vec<String> = vec![];
         if let Some(force) = &self.force {
             queries.push(format!("force={}", &force));
@@ -623,7 +1454,7 @@ impl DeleteMonitorRequest {
         };
 
         client
-            .delete::<DeleteMonitorRequest, DeleteMonitorResponse>(&path_and_query, &self)
+            .delete::<DeleteMonitorRequest, DeleteMonitorResponse>(&path_and_query, self)
             .await
     }
 }
@@ -0,0 +1,117 @@
+//! Mute and unmute monitors, as a whole or for a single scope, until an optional end time.
+//!
+//! Muted scopes show up in [`MonitorOptions::silenced`]. Muting is deprecated by Datadog in favour of downtimes.
+use crate::client::*;
+use serde::{Deserialize, Serialize};
+
+use super::{CreateMonitorResponse, MonitorOptions};
+
+/// Mute the specified monitor, or one of its scopes.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MuteMonitorRequest {
+    // Path
+    /// The ID of the monitor.
+    #[serde(skip_serializing)]
+    pub monitor_id: u64,
+    // Body
+    /// The scope to mute, e.g. `role:db` or `host:web-1`. Mutes the whole monitor when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// POSIX timestamp for when the mute should end. Mutes until unmuted when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+}
+impl MuteMonitorRequest {
+    pub fn monitor_id(mut self, monitor_id: u64) -> MuteMonitorRequest {
+        self.monitor_id = monitor_id;
+        self
+    }
+    pub fn scope(mut self, scope: &str) -> MuteMonitorRequest {
+        self.scope = Some(scope.into());
+        self
+    }
+    pub fn end(mut self, end: i64) -> MuteMonitorRequest {
+        self.end = Some(end);
+        self
+    }
+}
+
+impl MuteMonitorRequest {
+    pub async fn send(&self, client: &Client) -> DatadogResult<MuteMonitorResponse> {
+        let path_and_query = format!("/api/v1/monitor/{}/mute", &self.monitor_id);
+
+        client
+            .post::<MuteMonitorRequest, MuteMonitorResponse>(&path_and_query, self)
+            .await
+    }
+}
+
+/// Object describing a monitor
+pub type MuteMonitorResponse = CreateMonitorResponse;
+
+/// Unmute the specified monitor, one of its scopes, or all of its scopes.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnmuteMonitorRequest {
+    // Path
+    /// The ID of the monitor.
+    #[serde(skip_serializing)]
+    pub monitor_id: u64,
+    // Body
+    /// The scope to unmute, e.g. `role:db`. Unmutes the whole monitor when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Clear muting across all scopes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_scopes: Option<bool>,
+}
+impl UnmuteMonitorRequest {
+    pub fn monitor_id(mut self, monitor_id: u64) -> UnmuteMonitorRequest {
+        self.monitor_id = monitor_id;
+        self
+    }
+    pub fn scope(mut self, scope: &str) -> UnmuteMonitorRequest {
+        self.scope = Some(scope.into());
+        self
+    }
+    pub fn all_scopes(mut self, all_scopes: bool) -> UnmuteMonitorRequest {
+        self.all_scopes = Some(all_scopes);
+        self
+    }
+}
+
+impl UnmuteMonitorRequest {
+    pub async fn send(&self, client: &Client) -> DatadogResult<UnmuteMonitorResponse> {
+        let path_and_query = format!("/api/v1/monitor/{}/unmute", &self.monitor_id);
+
+        client
+            .post::<UnmuteMonitorRequest, UnmuteMonitorResponse>(&path_and_query, self)
+            .await
+    }
+}
+
+/// Object describing a monitor
+pub type UnmuteMonitorResponse = CreateMonitorResponse;
+
+impl MonitorOptions {
+    /// Mute a scope, `*` for the whole monitor, until the POSIX timestamp `end`, or until unmuted when `None`.
+    pub fn silence(mut self, scope: &str, end: Option<i64>) -> MonitorOptions {
+        self.silenced.get_or_insert_with(Default::default).insert(scope.into(), end);
+        self
+    }
+
+    /// Whether the scope is muted at the POSIX timestamp `now`, either by itself or by muting the whole monitor.
+    // `Option::is_none_or` needs Rust 1.82.
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn is_silenced(&self, scope: &str, now: i64) -> bool {
+        let silenced = match &self.silenced {
+            Some(silenced) => silenced,
+            None => return false,
+        };
+        ["*", scope]
+            .iter()
+            .filter_map(|scope| silenced.get(*scope))
+            .any(|end| end.map_or(true, |end| end > now))
+    }
+}
@@ -0,0 +1,989 @@
+//! Typed metric monitor queries, e.g. `avg(last_5m):avg:system.cpu.user{env:prod} by {host} > 90`.
+use std::{error, fmt, ops};
+use std::str::FromStr;
+
+use super::{CreateMonitorRequest, ThresholdValue, Thresholds};
+
+/// Error returned when a monitor query string cannot be parsed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct QueryParseError {
+    /// What was expected or found.
+    pub message: String,
+    /// Byte offset in the query where the error was found.
+    pub position: usize,
+}
+
+impl fmt::Display for QueryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+impl error::Error for QueryParseError {}
+
+/// Aggregation applied over the evaluation window. Allowed values: `avg`, `sum`, `min`, `max`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeAggregator {
+    Avg,
+    Sum,
+    Min,
+    Max,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for TimeAggregator {
+    fn default() -> TimeAggregator {
+        TimeAggregator::Avg
+    }
+}
+
+/// Compare the aggregated value with the value `shift` ago, for `change` and `pct_change` monitors.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeType {
+    /// Absolute change, `change(...)`.
+    Change,
+    /// Percentage change, `pct_change(...)`.
+    PctChange,
+}
+
+/// Time aggregation and evaluation window of a metric monitor, e.g. `avg(last_5m)` or `pct_change(avg(last_5m),last_1h)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeAggregation {
+    /// Aggregation applied over the evaluation window.
+    pub aggregator: TimeAggregator,
+    /// The evaluation window, e.g. `last_5m`.
+    pub window: String,
+    /// Whether to alert on the change of the value instead of the value itself.
+    pub change: Option<ChangeType>,
+    /// The time to compare with for `change` and `pct_change`, e.g. `last_1h`.
+    pub shift: Option<String>,
+}
+impl Default for TimeAggregation {
+    fn default() -> TimeAggregation {
+        TimeAggregation {
+            aggregator: TimeAggregator::Avg,
+            window: "last_5m".into(),
+            change: None,
+            shift: None,
+        }
+    }
+}
+
+/// Aggregation applied across the reporting sources of a metric. Allowed values: `avg`, `sum`, `min`, `max`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpaceAggregator {
+    Avg,
+    Sum,
+    Min,
+    Max,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for SpaceAggregator {
+    fn default() -> SpaceAggregator {
+        SpaceAggregator::Avg
+    }
+}
+
+/// A method applied to a metric query, e.g. `.rollup(sum, 60)` or `.as_count()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricMethod {
+    /// `.rollup(method, interval)`
+    Rollup { method: String, interval: Option<u64> },
+    /// `.as_count()`
+    AsCount,
+    /// `.as_rate()`
+    AsRate,
+    /// `.fill(value)`
+    Fill(String),
+    /// Any other method, with its raw arguments.
+    Other { name: String, args: String },
+}
+
+/// A single metric queried by the monitor, e.g. `avg:system.cpu.user{env:prod} by {host}`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MetricQuery {
+    /// Aggregation applied across the reporting sources of the metric.
+    pub space_aggregation: SpaceAggregator,
+    /// Name of the metric.
+    pub metric: String,
+    /// Tags the metric is filtered on. Empty means every source, i.e. `{*}`.
+    pub scope: Vec<String>,
+    /// Tags the monitor is broken down on.
+    pub group_by: Vec<String>,
+    /// Methods applied to the metric, in order.
+    pub methods: Vec<MetricMethod>,
+}
+impl MetricQuery {
+    pub fn space_aggregation(mut self, space_aggregation: SpaceAggregator) -> MetricQuery {
+        self.space_aggregation = space_aggregation;
+        self
+    }
+    pub fn metric(mut self, metric: &str) -> MetricQuery {
+        self.metric = metric.into();
+        self
+    }
+    pub fn scope(mut self, tag: &str) -> MetricQuery {
+        self.scope.push(tag.into());
+        self
+    }
+    pub fn group_by(mut self, tag: &str) -> MetricQuery {
+        self.group_by.push(tag.into());
+        self
+    }
+    pub fn rollup(mut self, method: &str, interval: Option<u64>) -> MetricQuery {
+        self.methods.push(MetricMethod::Rollup { method: method.into(), interval });
+        self
+    }
+    pub fn as_count(mut self) -> MetricQuery {
+        self.methods.push(MetricMethod::AsCount);
+        self
+    }
+    pub fn as_rate(mut self) -> MetricQuery {
+        self.methods.push(MetricMethod::AsRate);
+        self
+    }
+}
+
+/// Function applied to a query expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryFunction {
+    Anomalies,
+    Forecast,
+    Outliers,
+    /// Any other function, e.g. `abs` or `per_second`.
+    Other(String),
+}
+
+/// An argument of a function call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FunctionArg {
+    /// A nested expression or number.
+    Expression(QueryExpression),
+    /// A quoted string, e.g. `'basic'`.
+    String(String),
+    /// A named argument, e.g. `direction='above'`.
+    Named { name: String, value: Box<FunctionArg> },
+}
+
+/// Arithmetic operator between two expressions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+impl ArithmeticOperator {
+    fn precedence(&self) -> u8 {
+        match self {
+            ArithmeticOperator::Add | ArithmeticOperator::Subtract => 1,
+            ArithmeticOperator::Multiply | ArithmeticOperator::Divide => 2,
+        }
+    }
+}
+
+/// The evaluated part of a metric monitor query, between the time aggregation and the comparator.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryExpression {
+    Metric(MetricQuery),
+    Number(f64),
+    Function { function: QueryFunction, args: Vec<FunctionArg> },
+    Binary { operator: ArithmeticOperator, left: Box<QueryExpression>, right: Box<QueryExpression> },
+    /// An expression in parentheses.
+    Group(Box<QueryExpression>),
+}
+impl Default for QueryExpression {
+    fn default() -> QueryExpression {
+        QueryExpression::Metric(MetricQuery::default())
+    }
+}
+impl QueryExpression {
+    /// `anomalies(expression, 'algorithm', bounds)`
+    pub fn anomalies(expression: QueryExpression, algorithm: &str, bounds: f64) -> QueryExpression {
+        QueryExpression::Function {
+            function: QueryFunction::Anomalies,
+            args: vec![
+                FunctionArg::Expression(expression),
+                FunctionArg::String(algorithm.into()),
+                FunctionArg::Expression(QueryExpression::Number(bounds)),
+            ],
+        }
+    }
+    /// `forecast(expression, 'algorithm', deviations)`
+    pub fn forecast(expression: QueryExpression, algorithm: &str, deviations: f64) -> QueryExpression {
+        QueryExpression::Function {
+            function: QueryFunction::Forecast,
+            args: vec![
+                FunctionArg::Expression(expression),
+                FunctionArg::String(algorithm.into()),
+                FunctionArg::Expression(QueryExpression::Number(deviations)),
+            ],
+        }
+    }
+    /// `outliers(expression, 'algorithm', tolerance)`
+    pub fn outliers(expression: QueryExpression, algorithm: &str, tolerance: f64) -> QueryExpression {
+        QueryExpression::Function {
+            function: QueryFunction::Outliers,
+            args: vec![
+                FunctionArg::Expression(expression),
+                FunctionArg::String(algorithm.into()),
+                FunctionArg::Expression(QueryExpression::Number(tolerance)),
+            ],
+        }
+    }
+    /// Add a named argument to a function, e.g. `direction='above'`.
+    pub fn named_arg(mut self, name: &str, value: &str) -> QueryExpression {
+        if let QueryExpression::Function { args, .. } = &mut self {
+            args.push(FunctionArg::Named {
+                name: name.into(),
+                value: Box::new(FunctionArg::String(value.into())),
+            });
+        }
+        self
+    }
+    /// Wrap the expression in parentheses.
+    pub fn group(self) -> QueryExpression {
+        QueryExpression::Group(Box::new(self))
+    }
+    /// Every metric queried by the expression.
+    pub fn metrics(&self) -> Vec<&MetricQuery> {
+        match self {
+            QueryExpression::Metric(metric) => vec![metric],
+            QueryExpression::Number(_) => vec![],
+            QueryExpression::Function { args, .. } => args.iter().flat_map(|arg| arg.metrics()).collect(),
+            QueryExpression::Binary { left, right, .. } => left.metrics().into_iter().chain(right.metrics()).collect(),
+            QueryExpression::Group(expression) => expression.metrics(),
+        }
+    }
+    fn binary(self, operator: ArithmeticOperator, right: QueryExpression) -> QueryExpression {
+        QueryExpression::Binary {
+            operator,
+            left: Box::new(self),
+            right: Box::new(right),
+        }
+    }
+}
+impl FunctionArg {
+    fn metrics(&self) -> Vec<&MetricQuery> {
+        match self {
+            FunctionArg::Expression(expression) => expression.metrics(),
+            FunctionArg::String(_) => vec![],
+            FunctionArg::Named { value, .. } => value.metrics(),
+        }
+    }
+}
+
+impl From<MetricQuery> for QueryExpression {
+    fn from(metric: MetricQuery) -> QueryExpression {
+        QueryExpression::Metric(metric)
+    }
+}
+impl From<f64> for QueryExpression {
+    fn from(number: f64) -> QueryExpression {
+        QueryExpression::Number(number)
+    }
+}
+impl<T: Into<QueryExpression>> ops::Add<T> for QueryExpression {
+    type Output = QueryExpression;
+    fn add(self, right: T) -> QueryExpression {
+        self.binary(ArithmeticOperator::Add, right.into())
+    }
+}
+impl<T: Into<QueryExpression>> ops::Sub<T> for QueryExpression {
+    type Output = QueryExpression;
+    fn sub(self, right: T) -> QueryExpression {
+        self.binary(ArithmeticOperator::Subtract, right.into())
+    }
+}
+impl<T: Into<QueryExpression>> ops::Mul<T> for QueryExpression {
+    type Output = QueryExpression;
+    fn mul(self, right: T) -> QueryExpression {
+        self.binary(ArithmeticOperator::Multiply, right.into())
+    }
+}
+impl<T: Into<QueryExpression>> ops::Div<T> for QueryExpression {
+    type Output = QueryExpression;
+    fn div(self, right: T) -> QueryExpression {
+        self.binary(ArithmeticOperator::Divide, right.into())
+    }
+}
+
+/// Comparator between the evaluated value and the threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparator {
+    /// `>`
+    Above,
+    /// `>=`
+    AboveOrEqual,
+    /// `<`
+    Below,
+    /// `<=`
+    BelowOrEqual,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+}
+#[allow(clippy::derivable_impls)]
+impl Default for Comparator {
+    fn default() -> Comparator {
+        Comparator::Above
+    }
+}
+
+/// A metric monitor query: `time_aggr(time_window):expression comparator threshold`.
+///
+/// Build one with the builder methods and `to_string()` it, or parse an existing query with `parse()`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MonitorQuery {
+    /// Time aggregation and evaluation window.
+    pub time_aggregation: TimeAggregation,
+    /// The evaluated expression.
+    pub expression: QueryExpression,
+    /// Comparator between the evaluated value and the threshold.
+    pub comparator: Comparator,
+    /// The critical threshold, which must match `options.thresholds.critical`.
+    pub threshold: Option<ThresholdValue>,
+}
+impl MonitorQuery {
+    pub fn time_aggregation(mut self, aggregator: TimeAggregator, window: &str) -> MonitorQuery {
+        self.time_aggregation.aggregator = aggregator;
+        self.time_aggregation.window = window.into();
+        self
+    }
+    pub fn change(mut self, change: ChangeType, shift: &str) -> MonitorQuery {
+        self.time_aggregation.change = Some(change);
+        self.time_aggregation.shift = Some(shift.into());
+        self
+    }
+    pub fn expression<T: Into<QueryExpression>>(mut self, expression: T) -> MonitorQuery {
+        self.expression = expression.into();
+        self
+    }
+    pub fn comparator(mut self, comparator: Comparator) -> MonitorQuery {
+        self.comparator = comparator;
+        self
+    }
+    pub fn threshold<T: Into<ThresholdValue>>(mut self, threshold: T) -> MonitorQuery {
+        self.threshold = Some(threshold.into());
+        self
+    }
+
+    /// Parse a metric monitor query string.
+    pub fn parse(query: &str) -> Result<MonitorQuery, QueryParseError> {
+        let mut parser = Parser::new(query);
+        let query = parser.monitor_query()?;
+        parser.end()?;
+        Ok(query)
+    }
+
+    /// Check the query's threshold against the monitor thresholds.
+    ///
+    /// `critical` must equal the query threshold, and `warning` must trigger before `critical` given the comparator.
+    pub fn check_thresholds(&self, thresholds: &Thresholds) -> Result<(), String> {
+        check_thresholds(self.comparator, self.threshold, thresholds)
+    }
+}
+
+/// Check a query's comparator and threshold against the monitor thresholds.
+pub(crate) fn check_thresholds(comparator: Comparator, threshold: Option<ThresholdValue>, thresholds: &Thresholds) -> Result<(), String> {
+    let threshold = match threshold {
+        Some(threshold) => threshold.as_f64(),
+        None => return Err("The query has no threshold".into()),
+    };
+    if let Some(critical) = thresholds.critical {
+        if critical.as_f64() != threshold {
+            return Err(format!("The critical threshold {} does not match the query threshold {}", critical.as_f64(), threshold));
+        }
+    }
+    if let Some(warning) = thresholds.warning {
+        let warning = warning.as_f64();
+        let ordered = match comparator {
+            Comparator::Above | Comparator::AboveOrEqual => warning <= threshold,
+            Comparator::Below | Comparator::BelowOrEqual => warning >= threshold,
+            Comparator::Equal | Comparator::NotEqual => true,
+        };
+        if !ordered {
+            return Err(format!("The warning threshold {} is past the critical threshold {}", warning, threshold));
+        }
+    }
+    Ok(())
+}
+
+impl CreateMonitorRequest {
+    /// Set the query from a typed metric query, setting `options.thresholds.critical` to its threshold if not set yet.
+    pub fn monitor_query(mut self, query: &MonitorQuery) -> CreateMonitorRequest {
+        self.query = query.to_string();
+        self.default_critical(query.threshold)
+    }
+
+    pub(crate) fn default_critical(mut self, threshold: Option<ThresholdValue>) -> CreateMonitorRequest {
+        if let Some(threshold) = threshold {
+            let mut options = self.options.take().unwrap_or_default();
+            let mut thresholds = options.thresholds.take().unwrap_or_default();
+            if thresholds.critical.is_none() {
+                thresholds.critical = Some(threshold);
+            }
+            options.thresholds = Some(thresholds);
+            self.options = Some(options);
+        }
+        self
+    }
+}
+
+impl FromStr for MonitorQuery {
+    type Err = QueryParseError;
+    fn from_str(query: &str) -> Result<MonitorQuery, QueryParseError> {
+        MonitorQuery::parse(query)
+    }
+}
+
+impl fmt::Display for TimeAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TimeAggregator::Avg => "avg",
+            TimeAggregator::Sum => "sum",
+            TimeAggregator::Min => "min",
+            TimeAggregator::Max => "max",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for SpaceAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SpaceAggregator::Avg => "avg",
+            SpaceAggregator::Sum => "sum",
+            SpaceAggregator::Min => "min",
+            SpaceAggregator::Max => "max",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for TimeAggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let window = format!("{}({})", self.aggregator, self.window);
+        match &self.change {
+            Some(ChangeType::Change) => write!(f, "change({},{})", window, self.shift.as_deref().unwrap_or_default()),
+            Some(ChangeType::PctChange) => write!(f, "pct_change({},{})", window, self.shift.as_deref().unwrap_or_default()),
+            None => write!(f, "{}", window),
+        }
+    }
+}
+
+impl fmt::Display for MetricMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricMethod::Rollup { method, interval: Some(interval) } => write!(f, ".rollup({}, {})", method, interval),
+            MetricMethod::Rollup { method, interval: None } => write!(f, ".rollup({})", method),
+            MetricMethod::AsCount => write!(f, ".as_count()"),
+            MetricMethod::AsRate => write!(f, ".as_rate()"),
+            MetricMethod::Fill(value) => write!(f, ".fill({})", value),
+            MetricMethod::Other { name, args } => write!(f, ".{}({})", name, args),
+        }
+    }
+}
+
+impl fmt::Display for MetricQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scope = match self.scope.len() {
+            0 => "*".to_string(),
+            _ => self.scope.join(","),
+        };
+        write!(f, "{}:{}{{{}}}", self.space_aggregation, self.metric, scope)?;
+        if !self.group_by.is_empty() {
+            write!(f, " by {{{}}}", self.group_by.join(","))?;
+        }
+        for method in &self.methods {
+            write!(f, "{}", method)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for QueryFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryFunction::Anomalies => write!(f, "anomalies"),
+            QueryFunction::Forecast => write!(f, "forecast"),
+            QueryFunction::Outliers => write!(f, "outliers"),
+            QueryFunction::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl fmt::Display for FunctionArg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FunctionArg::Expression(expression) => write!(f, "{}", expression),
+            FunctionArg::String(value) => write!(f, "'{}'", value),
+            FunctionArg::Named { name, value } => write!(f, "{}={}", name, value),
+        }
+    }
+}
+
+impl fmt::Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self {
+            ArithmeticOperator::Add => "+",
+            ArithmeticOperator::Subtract => "-",
+            ArithmeticOperator::Multiply => "*",
+            ArithmeticOperator::Divide => "/",
+        };
+        write!(f, "{}", operator)
+    }
+}
+
+impl fmt::Display for QueryExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryExpression::Metric(metric) => write!(f, "{}", metric),
+            QueryExpression::Number(number) => write!(f, "{}", number),
+            QueryExpression::Function { function, args } => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{}({})", function, args.join(", "))
+            }
+            QueryExpression::Binary { operator, left, right } => {
+                // Add the parentheses the tree needs but that were not written explicitly
+                let left = match left.as_ref() {
+                    QueryExpression::Binary { operator: inner, .. } if inner.precedence() < operator.precedence() => format!("({})", left),
+                    _ => left.to_string(),
+                };
+                let right = match right.as_ref() {
+                    QueryExpression::Binary { operator: inner, .. }
+                        if inner.precedence() < operator.precedence()
+                            || (inner.precedence() == operator.precedence()
+                                && matches!(operator, ArithmeticOperator::Subtract | ArithmeticOperator::Divide)) =>
+                    {
+                        format!("({})", right)
+                    }
+                    _ => right.to_string(),
+                };
+                write!(f, "{} {} {}", left, operator, right)
+            }
+            QueryExpression::Group(expression) => write!(f, "({})", expression),
+        }
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let comparator = match self {
+            Comparator::Above => ">",
+            Comparator::AboveOrEqual => ">=",
+            Comparator::Below => "<",
+            Comparator::BelowOrEqual => "<=",
+            Comparator::Equal => "==",
+            Comparator::NotEqual => "!=",
+        };
+        write!(f, "{}", comparator)
+    }
+}
+
+impl fmt::Display for ThresholdValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThresholdValue::Integer(value) => write!(f, "{}", value),
+            ThresholdValue::Float(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for MonitorQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.time_aggregation, self.expression)?;
+        if let Some(threshold) = &self.threshold {
+            write!(f, " {} {}", self.comparator, threshold)?;
+        }
+        Ok(())
+    }
+}
+
+/// Recursive descent parser over a monitor query string.
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(input: &'a str) -> Parser<'a> {
+        Parser { input, pos: 0 }
+    }
+
+    pub(crate) fn error(&self, message: &str) -> QueryParseError {
+        QueryParseError {
+            message: message.into(),
+            position: self.pos,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    /// Consume `token` after any whitespace, returning whether it was there.
+    pub(crate) fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        match self.rest().starts_with(token) {
+            true => {
+                self.pos += token.len();
+                true
+            }
+            false => false,
+        }
+    }
+
+    pub(crate) fn expect(&mut self, token: &str) -> Result<(), QueryParseError> {
+        match self.eat(token) {
+            true => Ok(()),
+            false => Err(self.error(&format!("Expected `{}`", token))),
+        }
+    }
+
+    pub(crate) fn end(&mut self) -> Result<(), QueryParseError> {
+        self.skip_whitespace();
+        match self.pos == self.input.len() {
+            true => Ok(()),
+            false => Err(self.error("Unexpected trailing input")),
+        }
+    }
+
+    /// Read while `accept` holds, after any whitespace.
+    pub(crate) fn take_while<F: Fn(char) -> bool>(&mut self, accept: F) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c: char| !accept(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    pub(crate) fn identifier(&mut self) -> Result<&'a str, QueryParseError> {
+        let identifier = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+        match identifier.is_empty() {
+            true => Err(self.error("Expected an identifier")),
+            false => Ok(identifier),
+        }
+    }
+
+    /// Read up to the closing delimiter, allowing nested `(`/`)` pairs and quotes.
+    pub(crate) fn until_closing(&mut self, close: char) -> Result<&'a str, QueryParseError> {
+        let rest = self.rest();
+        let mut depth = 0;
+        let mut quote: Option<char> = None;
+        for (index, c) in rest.char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '\'') | (None, '"') => quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, c) if c == close && depth == 0 => {
+                    self.pos += index;
+                    return Ok(&rest[..index]);
+                }
+                (None, ')') => depth -= 1,
+                _ => {}
+            }
+        }
+        Err(self.error(&format!("Expected `{}`", close)))
+    }
+
+    /// A double quoted string with backslash escapes, unescaped.
+    pub(crate) fn escaped(&mut self) -> Result<String, QueryParseError> {
+        self.expect("\"")?;
+        let mut value = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => value.push(escaped),
+                    None => break,
+                },
+                '"' => {
+                    self.pos += index + 1;
+                    return Ok(value);
+                }
+                c => value.push(c),
+            }
+        }
+        Err(self.error("Unterminated string"))
+    }
+
+    /// A quoted string, without its quotes.
+    pub(crate) fn quoted(&mut self) -> Result<&'a str, QueryParseError> {
+        self.skip_whitespace();
+        let quote = match self.peek() {
+            Some(c) if c == '\'' || c == '"' => c,
+            _ => return Err(self.error("Expected a quoted string")),
+        };
+        self.pos += 1;
+        let rest = self.rest();
+        match rest.find(quote) {
+            Some(len) => {
+                self.pos += len + 1;
+                Ok(&rest[..len])
+            }
+            None => Err(self.error("Unterminated string")),
+        }
+    }
+
+    pub(crate) fn number(&mut self) -> Result<ThresholdValue, QueryParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        let digits = self.rest();
+        let len = digits
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E'))
+            .unwrap_or(digits.len());
+        self.pos += len;
+        let text = &self.input[start..self.pos];
+        if let Ok(value) = text.parse::<i64>() {
+            return Ok(ThresholdValue::Integer(value));
+        }
+        match text.parse::<f64>() {
+            Ok(value) => Ok(ThresholdValue::Float(value)),
+            Err(_) => {
+                self.pos = start;
+                Err(self.error("Expected a number"))
+            }
+        }
+    }
+
+    pub(crate) fn comparator(&mut self) -> Result<Comparator, QueryParseError> {
+        for (token, comparator) in [
+            (">=", Comparator::AboveOrEqual),
+            ("<=", Comparator::BelowOrEqual),
+            ("==", Comparator::Equal),
+            ("!=", Comparator::NotEqual),
+            (">", Comparator::Above),
+            ("<", Comparator::Below),
+        ] {
+            if self.eat(token) {
+                return Ok(comparator);
+            }
+        }
+        Err(self.error("Expected a comparator"))
+    }
+
+    fn monitor_query(&mut self) -> Result<MonitorQuery, QueryParseError> {
+        let time_aggregation = self.time_aggregation()?;
+        self.expect(":")?;
+        let expression = self.expression()?;
+        self.skip_whitespace();
+        let (comparator, threshold) = match self.peek() {
+            None => (Comparator::default(), None),
+            Some(_) => (self.comparator()?, Some(self.number()?)),
+        };
+        Ok(MonitorQuery { time_aggregation, expression, comparator, threshold })
+    }
+
+    fn time_aggregation(&mut self) -> Result<TimeAggregation, QueryParseError> {
+        let name = self.identifier()?;
+        let change = match name {
+            "change" => Some(ChangeType::Change),
+            "pct_change" => Some(ChangeType::PctChange),
+            _ => None,
+        };
+        self.expect("(")?;
+        let aggregator = match change {
+            Some(_) => {
+                let aggregator = self.time_aggregator()?;
+                self.expect("(")?;
+                aggregator
+            }
+            None => self.time_aggregator_named(name)?,
+        };
+        let window = self.identifier()?.to_string();
+        self.expect(")")?;
+        let shift = match change {
+            Some(_) => {
+                self.expect(",")?;
+                let shift = self.identifier()?.to_string();
+                self.expect(")")?;
+                Some(shift)
+            }
+            None => None,
+        };
+        Ok(TimeAggregation { aggregator, window, change, shift })
+    }
+
+    fn time_aggregator(&mut self) -> Result<TimeAggregator, QueryParseError> {
+        let name = self.identifier()?;
+        self.time_aggregator_named(name)
+    }
+
+    fn time_aggregator_named(&self, name: &str) -> Result<TimeAggregator, QueryParseError> {
+        match name {
+            "avg" => Ok(TimeAggregator::Avg),
+            "sum" => Ok(TimeAggregator::Sum),
+            "min" => Ok(TimeAggregator::Min),
+            "max" => Ok(TimeAggregator::Max),
+            _ => Err(self.error(&format!("Unknown time aggregation `{}`", name))),
+        }
+    }
+
+    fn expression(&mut self) -> Result<QueryExpression, QueryParseError> {
+        let mut left = self.term()?;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                Some('+') => ArithmeticOperator::Add,
+                Some('-') => ArithmeticOperator::Subtract,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.term()?;
+            left = left.binary(operator, right);
+        }
+    }
+
+    fn term(&mut self) -> Result<QueryExpression, QueryParseError> {
+        let mut left = self.factor()?;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                Some('*') => ArithmeticOperator::Multiply,
+                Some('/') => ArithmeticOperator::Divide,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.factor()?;
+            left = left.binary(operator, right);
+        }
+    }
+
+    fn factor(&mut self) -> Result<QueryExpression, QueryParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let expression = self.expression()?;
+                self.expect(")")?;
+                Ok(QueryExpression::Group(Box::new(expression)))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' => Ok(QueryExpression::Number(self.number()?.as_f64())),
+            Some(_) => {
+                let start = self.pos;
+                let name = self.identifier()?;
+                match self.peek() {
+                    Some(':') => {
+                        self.pos = start;
+                        Ok(QueryExpression::Metric(self.metric_query()?))
+                    }
+                    Some('(') => {
+                        self.pos += 1;
+                        let function = match name {
+                            "anomalies" => QueryFunction::Anomalies,
+                            "forecast" => QueryFunction::Forecast,
+                            "outliers" => QueryFunction::Outliers,
+                            name => QueryFunction::Other(name.into()),
+                        };
+                        let args = self.function_args()?;
+                        Ok(QueryExpression::Function { function, args })
+                    }
+                    _ => Err(self.error("Expected `:` or `(`")),
+                }
+            }
+            None => Err(self.error("Unexpected end of query")),
+        }
+    }
+
+    fn function_args(&mut self) -> Result<Vec<FunctionArg>, QueryParseError> {
+        let mut args = vec![];
+        if self.eat(")") {
+            return Ok(args);
+        }
+        loop {
+            args.push(self.function_arg()?);
+            if self.eat(")") {
+                return Ok(args);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    fn function_arg(&mut self) -> Result<FunctionArg, QueryParseError> {
+        self.skip_whitespace();
+        if let Some('\'') | Some('"') = self.peek() {
+            return Ok(FunctionArg::String(self.quoted()?.into()));
+        }
+        let start = self.pos;
+        if let Ok(name) = self.identifier() {
+            if self.rest().starts_with('=') && !self.rest().starts_with("==") {
+                self.pos += 1;
+                let value = self.function_arg()?;
+                return Ok(FunctionArg::Named { name: name.into(), value: Box::new(value) });
+            }
+        }
+        self.pos = start;
+        Ok(FunctionArg::Expression(self.expression()?))
+    }
+
+    fn metric_query(&mut self) -> Result<MetricQuery, QueryParseError> {
+        let space_aggregation = match self.identifier()? {
+            "avg" => SpaceAggregator::Avg,
+            "sum" => SpaceAggregator::Sum,
+            "min" => SpaceAggregator::Min,
+            "max" => SpaceAggregator::Max,
+            name => return Err(self.error(&format!("Unknown space aggregation `{}`", name))),
+        };
+        self.expect(":")?;
+        let metric = self.identifier()?.to_string();
+        self.expect("{")?;
+        let scope = split_tags(self.until_closing('}')?);
+        let scope = match scope.as_slice() {
+            [all] if all == "*" => vec![],
+            _ => scope,
+        };
+        self.expect("}")?;
+        let mut group_by = vec![];
+        let start = self.pos;
+        if self.eat("by") && self.eat("{") {
+            group_by = split_tags(self.until_closing('}')?);
+            self.expect("}")?;
+        } else {
+            self.pos = start;
+        }
+        let mut methods = vec![];
+        while self.rest().starts_with('.') {
+            self.pos += 1;
+            let name = self.identifier()?;
+            self.expect("(")?;
+            let args = self.until_closing(')')?.trim();
+            self.expect(")")?;
+            methods.push(match name {
+                "rollup" => {
+                    let mut parts = args.splitn(2, ',').map(|part| part.trim());
+                    let method = parts.next().unwrap_or_default().to_string();
+                    let interval = match parts.next() {
+                        Some(interval) => Some(interval.parse().map_err(|_| self.error("Expected a rollup interval"))?),
+                        None => None,
+                    };
+                    MetricMethod::Rollup { method, interval }
+                }
+                "as_count" => MetricMethod::AsCount,
+                "as_rate" => MetricMethod::AsRate,
+                "fill" => MetricMethod::Fill(args.into()),
+                name => MetricMethod::Other { name: name.into(), args: args.into() },
+            });
+        }
+        Ok(MetricQuery { space_aggregation, metric, scope, group_by, methods })
+    }
+}
+
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
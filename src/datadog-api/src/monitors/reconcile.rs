@@ -0,0 +1,445 @@
+//! Sync monitor definitions kept as code, e.g. YAML in git, to an organization.
+//!
+//! Desired monitors are matched to existing ones by name, preferring monitors carrying the `managed-by:<owner>` tag.
+//! [`MonitorReconciler::plan`] computes the changes, which can be printed, and [`MonitorReconciler::apply`] makes them.
+use crate::client::*;
+use futures_util::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+use super::{
+    CreateMonitorRequest, DeleteMonitorRequest, EditMonitorRequest, GetMonitorRequest, GetMonitorResponse, ListMonitorsRequest,
+    MonitorsSearchRequest,
+};
+
+/// A file of desired monitor definitions.
+///
+/// ```yaml
+/// monitors:
+///   - name: High CPU
+///     type: metric alert
+///     query: avg(last_5m):avg:system.cpu.user{env:prod} by {host} > 90
+///     message: "{{#is_alert}}CPU is {{value}} @slack-ops{{/is_alert}}"
+///     options:
+///       thresholds:
+///         critical: 90
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorsFile {
+    pub monitors: Vec<CreateMonitorRequest>,
+}
+
+impl MonitorsFile {
+    pub fn from_yaml(yaml: &str) -> DatadogResult<MonitorsFile> {
+        Ok(serde_yaml::from_str::<MonitorsFile>(yaml)?)
+    }
+
+    pub fn read<P: AsRef<Path>>(path: P) -> DatadogResult<MonitorsFile> {
+        MonitorsFile::from_yaml(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// What a planned change does to a monitor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReconcileAction {
+    Create,
+    Update,
+    Delete,
+    NoOp,
+}
+
+/// A field whose value differs between the existing and the desired monitor, e.g. `options.thresholds.critical`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub field: String,
+    /// The existing value, `null` when unset.
+    pub before: Value,
+    /// The desired value, `null` when the monitor is deleted.
+    pub after: Value,
+}
+
+/// A change to a single monitor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedChange {
+    pub action: ReconcileAction,
+    /// The monitor name.
+    pub name: String,
+    /// The desired definition, `None` when the monitor is deleted.
+    pub desired: Option<CreateMonitorRequest>,
+    /// The existing monitor, `None` when the monitor is created.
+    pub existing: Option<GetMonitorResponse>,
+    /// Fields that differ.
+    pub diffs: Vec<FieldDiff>,
+}
+
+/// Changes needed to make the organization match the desired monitors.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReconcilePlan {
+    pub changes: Vec<PlannedChange>,
+}
+
+impl ReconcilePlan {
+    /// Changes with the given action.
+    pub fn with_action(&self, action: ReconcileAction) -> Vec<&PlannedChange> {
+        self.changes.iter().filter(|change| change.action == action).collect()
+    }
+
+    /// Whether applying the plan would change anything.
+    pub fn is_empty(&self) -> bool {
+        self.changes.iter().all(|change| change.action == ReconcileAction::NoOp)
+    }
+}
+
+/// Make the monitors of an organization match a list of desired monitors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorReconciler {
+    /// The desired monitors. Names must be unique.
+    pub monitors: Vec<CreateMonitorRequest>,
+    /// Value of the `managed-by` tag added to every desired monitor, which marks monitors owned by this reconciler.
+    pub managed_by: String,
+    /// Delete monitors tagged `managed-by:<managed_by>` that are not desired anymore.
+    pub prune: bool,
+    /// Number of changes applied at the same time.
+    pub concurrency: usize,
+}
+impl Default for MonitorReconciler {
+    fn default() -> MonitorReconciler {
+        MonitorReconciler {
+            monitors: vec![],
+            managed_by: "datadog-api".into(),
+            prune: false,
+            concurrency: 4,
+        }
+    }
+}
+impl MonitorReconciler {
+    pub fn monitors(mut self, monitors: Vec<CreateMonitorRequest>) -> MonitorReconciler {
+        self.monitors = monitors;
+        self
+    }
+    pub fn managed_by(mut self, managed_by: &str) -> MonitorReconciler {
+        self.managed_by = managed_by.into();
+        self
+    }
+    pub fn prune(mut self, prune: bool) -> MonitorReconciler {
+        self.prune = prune;
+        self
+    }
+    pub fn concurrency(mut self, concurrency: usize) -> MonitorReconciler {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+impl MonitorReconciler {
+    /// The tag marking monitors owned by this reconciler.
+    pub fn managed_tag(&self) -> String {
+        format!("managed-by:{}", self.managed_by)
+    }
+
+    /// The desired monitor with the managed tag added.
+    fn tagged(&self, monitor: &CreateMonitorRequest) -> CreateMonitorRequest {
+        let mut monitor = monitor.clone();
+        let managed_tag = self.managed_tag();
+        let tags = monitor.tags.get_or_insert_with(Vec::new);
+        if !tags.contains(&managed_tag) {
+            tags.push(managed_tag);
+        }
+        monitor
+    }
+
+    /// Fetch the managed monitors and the monitors named like a desired monitor, then plan against them.
+    pub async fn plan(&self, client: &Client) -> DatadogResult<ReconcilePlan> {
+        let search = MonitorsSearchRequest::default().query(&format!("tag:\"{}\"", self.managed_tag()));
+        let mut existing: Vec<GetMonitorResponse> = vec![];
+        for monitor in search.send_all(client).await? {
+            existing.push(GetMonitorRequest::default().monitor_id(monitor.id).send(client).await?);
+        }
+        for desired in &self.monitors {
+            let name = desired.name.clone().unwrap_or_default();
+            if existing.iter().any(|monitor| monitor.name == name) {
+                continue;
+            }
+            let named = ListMonitorsRequest::default().name(&name).send(client).await?;
+            existing.extend(named.into_iter().filter(|monitor| monitor.name == name));
+        }
+        self.plan_against(&existing)
+    }
+
+    /// Plan against the given existing monitors, without calling Datadog.
+    pub fn plan_against(&self, existing: &[GetMonitorResponse]) -> DatadogResult<ReconcilePlan> {
+        let managed_tag = self.managed_tag();
+        let is_managed = |monitor: &GetMonitorResponse| monitor.tags.contains(&managed_tag);
+        let mut names: HashSet<String> = HashSet::new();
+        let mut matched: HashSet<u64> = HashSet::new();
+        let mut changes: Vec<PlannedChange> = vec![];
+
+        for desired in &self.monitors {
+            let name = match &desired.name {
+                Some(name) if !name.is_empty() => name.clone(),
+                _ => return Err(format!("Desired monitor with query `{}` has no name", desired.query).into()),
+            };
+            if !names.insert(name.clone()) {
+                return Err(format!("Desired monitor `{}` is defined more than once", name).into());
+            }
+            let desired = self.tagged(desired);
+            let candidates = || existing.iter().filter(|monitor| monitor.name == name && !matched.contains(&monitor.id));
+            let found = candidates().find(|monitor| is_managed(monitor)).or_else(|| candidates().min_by_key(|monitor| monitor.id));
+            let change = match found {
+                Some(monitor) => {
+                    matched.insert(monitor.id);
+                    let diffs = diff_monitor(&EditMonitorRequest::from(monitor.clone()), &desired);
+                    PlannedChange {
+                        action: match diffs.is_empty() {
+                            true => ReconcileAction::NoOp,
+                            false => ReconcileAction::Update,
+                        },
+                        name,
+                        desired: Some(desired),
+                        existing: Some(monitor.clone()),
+                        diffs,
+                    }
+                }
+                None => PlannedChange {
+                    action: ReconcileAction::Create,
+                    name,
+                    diffs: diff_monitor(&Value::Null, &desired),
+                    desired: Some(desired),
+                    existing: None,
+                },
+            };
+            changes.push(change);
+        }
+
+        if self.prune {
+            let mut seen: HashSet<u64> = HashSet::new();
+            for monitor in existing {
+                if is_managed(monitor) && !matched.contains(&monitor.id) && seen.insert(monitor.id) {
+                    changes.push(PlannedChange {
+                        action: ReconcileAction::Delete,
+                        name: monitor.name.clone(),
+                        desired: None,
+                        existing: Some(monitor.clone()),
+                        diffs: vec![],
+                    });
+                }
+            }
+        }
+        Ok(ReconcilePlan { changes })
+    }
+
+    /// Apply the plan, running up to `concurrency` changes at a time.
+    ///
+    /// Failed changes don't stop the others. The report lists what was applied and how to roll it back.
+    pub async fn apply(&self, client: &Client, plan: &ReconcilePlan) -> ReconcileReport {
+        let results: Vec<(PlannedChange, DatadogResult<u64>)> = stream::iter(plan.changes.iter().filter(|change| change.action != ReconcileAction::NoOp))
+            .map(|change| async move { (change.clone(), apply_change(client, change).await) })
+            .buffer_unordered(self.concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut report = ReconcileReport::default();
+        for (change, result) in results {
+            match result {
+                Ok(monitor_id) => report.applied.push(AppliedChange { change, monitor_id }),
+                Err(error) => report.failed.push(FailedChange { change, error: error.to_string() }),
+            }
+        }
+        report.applied.sort_by(|a, b| a.change.name.cmp(&b.change.name));
+        report.failed.sort_by(|a, b| a.change.name.cmp(&b.change.name));
+        report
+    }
+}
+
+async fn apply_change(client: &Client, change: &PlannedChange) -> DatadogResult<u64> {
+    match (&change.action, &change.desired, &change.existing) {
+        (ReconcileAction::Create, Some(desired), _) => Ok(desired.send(client).await?.id),
+        (ReconcileAction::Update, Some(desired), Some(existing)) => {
+            let req = edit_request(existing.id, desired);
+            Ok(req.send(client).await?.id)
+        }
+        (ReconcileAction::Delete, _, Some(existing)) => {
+            DeleteMonitorRequest::default()
+                .monitor_id(existing.id)
+                .check_can_delete(true)
+                .send(client)
+                .await?;
+            Ok(existing.id)
+        }
+        _ => Err(format!("Nothing to apply for monitor `{}`", change.name).into()),
+    }
+}
+
+fn edit_request(monitor_id: u64, monitor: &CreateMonitorRequest) -> EditMonitorRequest {
+    EditMonitorRequest {
+        monitor_id,
+        message: monitor.message.clone(),
+        name: monitor.name.clone(),
+        options: monitor.options.clone(),
+        priority: monitor.priority,
+        query: monitor.query.clone(),
+        restricted_roles: monitor.restricted_roles.clone(),
+        tags: monitor.tags.clone(),
+        _type: monitor._type.clone(),
+    }
+}
+
+/// Field-level differences, limited to the fields set in the desired monitor, so values Datadog fills in by default don't show up.
+fn diff_monitor<E: Serialize>(existing: &E, desired: &CreateMonitorRequest) -> Vec<FieldDiff> {
+    let existing = serde_json::to_value(existing).unwrap_or(Value::Null);
+    let desired = serde_json::to_value(desired).unwrap_or(Value::Null);
+    let mut diffs = vec![];
+    diff_values("", &existing, &desired, &mut diffs);
+    diffs
+}
+
+fn diff_values(field: &str, before: &Value, after: &Value, diffs: &mut Vec<FieldDiff>) {
+    match after {
+        Value::Object(fields) => {
+            for (key, after) in fields {
+                let path = match field {
+                    "" => key.clone(),
+                    _ => format!("{}.{}", field, key),
+                };
+                diff_values(&path, before.get(key).unwrap_or(&Value::Null), after, diffs);
+            }
+        }
+        Value::Null => {}
+        _ if same_value(field, before, after) => {}
+        _ => diffs.push(FieldDiff {
+            field: field.into(),
+            before: before.clone(),
+            after: after.clone(),
+        }),
+    }
+}
+
+/// Compare numbers by value, e.g. `90` and `90.0`, and tags regardless of order.
+fn same_value(field: &str, before: &Value, after: &Value) -> bool {
+    match (before, after) {
+        (Value::Number(before), Value::Number(after)) => before.as_f64() == after.as_f64(),
+        (Value::Array(before), Value::Array(after)) if field == "tags" => {
+            let mut before: Vec<String> = before.iter().map(|tag| tag.to_string()).collect();
+            let mut after: Vec<String> = after.iter().map(|tag| tag.to_string()).collect();
+            before.sort();
+            after.sort();
+            before == after
+        }
+        (before, after) => before == after,
+    }
+}
+
+/// A change that was applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedChange {
+    pub change: PlannedChange,
+    /// The ID of the created, updated or deleted monitor.
+    pub monitor_id: u64,
+}
+
+/// A change that failed to apply.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedChange {
+    pub change: PlannedChange,
+    pub error: String,
+}
+
+/// How to undo an applied change.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RollbackStep {
+    /// Delete a monitor that was created.
+    Delete(DeleteMonitorRequest),
+    /// Restore the previous definition of an updated monitor.
+    Edit(EditMonitorRequest),
+    /// Recreate a deleted monitor. It gets a new ID.
+    Create(CreateMonitorRequest),
+}
+
+/// Outcome of applying a plan.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReconcileReport {
+    pub applied: Vec<AppliedChange>,
+    pub failed: Vec<FailedChange>,
+}
+
+impl ReconcileReport {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Requests that undo the applied changes.
+    pub fn rollback(&self) -> Vec<RollbackStep> {
+        self.applied
+            .iter()
+            .filter_map(|applied| match (&applied.change.action, &applied.change.existing) {
+                (ReconcileAction::Create, _) => Some(RollbackStep::Delete(DeleteMonitorRequest::default().monitor_id(applied.monitor_id))),
+                (ReconcileAction::Update, Some(existing)) => Some(RollbackStep::Edit(EditMonitorRequest::from(existing.clone()))),
+                (ReconcileAction::Delete, Some(existing)) => Some(RollbackStep::Create(CreateMonitorRequest::from(existing.clone()))),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for PlannedChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match &self.existing {
+            Some(existing) => format!(" ({})", existing.id),
+            None => "".into(),
+        };
+        match self.action {
+            ReconcileAction::Create => writeln!(f, "+ create `{}`", self.name)?,
+            ReconcileAction::Update => writeln!(f, "~ update `{}`{}", self.name, id)?,
+            ReconcileAction::Delete => writeln!(f, "- delete `{}`{}", self.name, id)?,
+            ReconcileAction::NoOp => writeln!(f, "  no-op `{}`{}", self.name, id)?,
+        }
+        for diff in &self.diffs {
+            match self.action {
+                ReconcileAction::Create => writeln!(f, "    {}: {}", diff.field, diff.after)?,
+                _ => writeln!(f, "    {}: {} -> {}", diff.field, diff.before, diff.after)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ReconcilePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            write!(f, "{}", change)?;
+        }
+        write!(
+            f,
+            "Plan: {} to create, {} to update, {} to delete, {} unchanged.",
+            self.with_action(ReconcileAction::Create).len(),
+            self.with_action(ReconcileAction::Update).len(),
+            self.with_action(ReconcileAction::Delete).len(),
+            self.with_action(ReconcileAction::NoOp).len(),
+        )
+    }
+}
+
+impl fmt::Display for ReconcileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for applied in &self.applied {
+            let action = match applied.change.action {
+                ReconcileAction::Create => "created",
+                ReconcileAction::Update => "updated",
+                ReconcileAction::Delete => "deleted",
+                ReconcileAction::NoOp => "unchanged",
+            };
+            writeln!(f, "{} `{}` ({})", action, applied.change.name, applied.monitor_id)?;
+        }
+        for failed in &self.failed {
+            writeln!(f, "failed `{}`: {}", failed.change.name, failed.error)?;
+        }
+        if !self.failed.is_empty() && !self.applied.is_empty() {
+            writeln!(f, "{} change(s) applied before the failure can be rolled back with `rollback()`.", self.applied.len())?;
+        }
+        write!(f, "Applied {} change(s), {} failed.", self.applied.len(), self.failed.len())
+    }
+}
@@ -0,0 +1,266 @@
+//! Validate monitor definitions and check whether monitors can be deleted before mutating them.
+use crate::client::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::{error, fmt};
+
+use super::CreateMonitorRequest;
+
+/// A single problem reported by Datadog about a monitor definition.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorValidationError {
+    /// The field the message refers to, e.g. `query` or `options.thresholds`, or the monitor ID for can-delete checks.
+    pub field: Option<String>,
+    /// The message returned by Datadog.
+    pub message: String,
+}
+
+impl fmt::Display for MonitorValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Problems reported by Datadog about a monitor definition, returned as the error of validation and can-delete checks.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorValidationErrors {
+    pub errors: Vec<MonitorValidationError>,
+}
+
+impl MonitorValidationErrors {
+    /// Map a Datadog error body into per-field messages.
+    ///
+    /// Datadog returns either a list of messages, or an object keyed by field whose values are a message or a list of messages.
+    pub fn from_body(body: &str) -> MonitorValidationErrors {
+        let errors = match serde_json::from_str::<Value>(body) {
+            Ok(value) => match value.get("errors") {
+                Some(Value::Array(messages)) => messages
+                    .iter()
+                    .map(|message| MonitorValidationError::from_message(&value_to_message(message)))
+                    .collect(),
+                Some(Value::Object(fields)) => fields
+                    .iter()
+                    .flat_map(|(field, messages)| {
+                        let messages = match messages {
+                            Value::Array(messages) => messages.iter().map(value_to_message).collect(),
+                            message => vec![value_to_message(message)],
+                        };
+                        messages.into_iter().map(move |message| MonitorValidationError {
+                            field: Some(field.clone()),
+                            message,
+                        })
+                    })
+                    .collect(),
+                _ => vec![MonitorValidationError::from_message(body)],
+            },
+            Err(_) => vec![MonitorValidationError::from_message(body)],
+        };
+        MonitorValidationErrors { errors }
+    }
+
+    /// Messages about the given field.
+    pub fn field(&self, field: &str) -> Vec<&MonitorValidationError> {
+        self.errors
+            .iter()
+            .filter(|error| error.field.as_deref() == Some(field))
+            .collect()
+    }
+}
+
+impl fmt::Display for MonitorValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.errors.iter().map(|error| error.to_string()).collect();
+        write!(f, "{}", messages.join("\n"))
+    }
+}
+impl error::Error for MonitorValidationErrors {}
+
+impl MonitorValidationError {
+    /// Find the field a plain message refers to, e.g. `The value provided for parameter 'query' is invalid` or `options.thresholds: ...`.
+    pub fn from_message(message: &str) -> MonitorValidationError {
+        let quoted = message
+            .split_once("parameter '")
+            .and_then(|(_, rest)| rest.split_once('\''))
+            .map(|(field, _)| field.to_string());
+        let prefixed = message
+            .split_once(": ")
+            .map(|(field, _)| field)
+            .filter(|field| field.starts_with(|c: char| c.is_ascii_lowercase()) && field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .map(|field| field.to_string());
+        let field = quoted.or(prefixed);
+        let message = match &field {
+            Some(field) => message.strip_prefix(&format!("{}: ", field)).unwrap_or(message),
+            None => message,
+        };
+        MonitorValidationError {
+            field,
+            message: message.to_string(),
+        }
+    }
+}
+
+fn value_to_message(value: &Value) -> String {
+    match value {
+        Value::String(message) => message.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Send a validation request, mapping a failure into [`MonitorValidationErrors`].
+async fn validate<T: Serialize>(client: &Client, path_and_query: &str, body: &T) -> DatadogResult<ValidateMonitorResponse> {
+    let resp = client.post_json(path_and_query, body).await?;
+    let success = resp.status().is_success();
+    let body = resp.text().await?;
+    match success {
+        true => Ok(serde_json::from_str::<ValidateMonitorResponse>(&body)?),
+        false => Err(Box::new(MonitorValidationErrors::from_body(&body))),
+    }
+}
+
+/// Validate the monitor provided in the request.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidateMonitorRequest {
+    /// The monitor definition to validate.
+    #[serde(flatten)]
+    pub monitor: CreateMonitorRequest,
+}
+impl ValidateMonitorRequest {
+    pub fn monitor(mut self, monitor: CreateMonitorRequest) -> ValidateMonitorRequest {
+        self.monitor = monitor;
+        self
+    }
+}
+
+impl ValidateMonitorRequest {
+    /// Returns [`MonitorValidationErrors`] as the error when the monitor is invalid.
+    pub async fn send(&self, client: &Client) -> DatadogResult<ValidateMonitorResponse> {
+        let path_and_query = "/api/v1/monitor/validate";
+
+        validate(client, path_and_query, &self).await
+    }
+}
+
+impl From<CreateMonitorRequest> for ValidateMonitorRequest {
+    fn from(monitor: CreateMonitorRequest) -> ValidateMonitorRequest {
+        ValidateMonitorRequest { monitor }
+    }
+}
+
+/// Validate the monitor provided in the request, as an edit of an existing monitor.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidateExistingMonitorRequest {
+    // Path
+    /// The ID of the monitor.
+    #[serde(skip_serializing)]
+    pub monitor_id: u64,
+    // Body
+    /// The monitor definition to validate.
+    #[serde(flatten)]
+    pub monitor: CreateMonitorRequest,
+}
+impl ValidateExistingMonitorRequest {
+    pub fn monitor_id(mut self, monitor_id: u64) -> ValidateExistingMonitorRequest {
+        self.monitor_id = monitor_id;
+        self
+    }
+    pub fn monitor(mut self, monitor: CreateMonitorRequest) -> ValidateExistingMonitorRequest {
+        self.monitor = monitor;
+        self
+    }
+}
+
+impl ValidateExistingMonitorRequest {
+    /// Returns [`MonitorValidationErrors`] as the error when the monitor is invalid.
+    pub async fn send(&self, client: &Client) -> DatadogResult<ValidateMonitorResponse> {
+        let path_and_query = format!("/api/v1/monitor/{}/validate", &self.monitor_id);
+
+        validate(client, &path_and_query, &self).await
+    }
+}
+
+/// Response of a successful validation. (Always empty)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidateMonitorResponse {}
+
+/// Check if the given monitors can be deleted.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CanDeleteMonitorsRequest {
+    // Query
+    /// The IDs of the monitor to check.
+    #[serde(skip_serializing)]
+    pub monitor_ids: Vec<u64>,
+}
+impl CanDeleteMonitorsRequest {
+    pub fn monitor_ids(mut self, monitor_ids: Vec<u64>) -> CanDeleteMonitorsRequest {
+        self.monitor_ids = monitor_ids;
+        self
+    }
+}
+
+impl CanDeleteMonitorsRequest {
+    /// Monitors that cannot be deleted are listed in the response's `errors`, not returned as an error.
+    pub async fn send(&self, client: &Client) -> DatadogResult<CanDeleteMonitorsResponse> {
+        let monitor_ids: Vec<String> = self.monitor_ids.iter().map(|id| id.to_string()).collect();
+        let path_and_query = format!("/api/v1/monitor/can_delete?monitor_ids={}", monitor_ids.join(","));
+
+        let resp = client.get_raw(&path_and_query).await?;
+        let status = resp.status();
+        let body = resp.text().await?;
+        match status.is_success() || status == reqwest::StatusCode::CONFLICT {
+            true => Ok(serde_json::from_str::<CanDeleteMonitorsResponse>(&body)?),
+            false => Err(Box::new(MonitorValidationErrors::from_body(&body))),
+        }
+    }
+}
+
+/// Wrapper object with the list of monitor IDs.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CanDeleteMonitorsData {
+    /// An array of Monitor IDs that can be deleted.
+    pub ok: Vec<u64>,
+}
+
+/// Response of monitor IDs that can or can't be safely deleted.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CanDeleteMonitorsResponse {
+    /// Wrapper object with the list of monitor IDs.
+    pub data: CanDeleteMonitorsData,
+    /// A mapping of Monitor ID to strings denoting where it's used.
+    pub errors: Option<HashMap<String, Vec<String>>>,
+}
+
+impl CanDeleteMonitorsResponse {
+    /// Whether the monitor can be safely deleted.
+    pub fn can_delete(&self, monitor_id: u64) -> bool {
+        self.data.ok.contains(&monitor_id)
+    }
+
+    /// The reasons monitors cannot be deleted, with the monitor ID as field.
+    pub fn validation_errors(&self) -> MonitorValidationErrors {
+        let mut errors: Vec<MonitorValidationError> = vec![];
+        if let Some(fields) = &self.errors {
+            for (field, messages) in fields {
+                for message in messages {
+                    errors.push(MonitorValidationError {
+                        field: Some(field.clone()),
+                        message: message.clone(),
+                    });
+                }
+            }
+        }
+        errors.sort_by(|a, b| a.field.cmp(&b.field));
+        MonitorValidationErrors { errors }
+    }
+}
@@ -0,0 +1,123 @@
+//! Watch monitor groups for status changes by polling the monitor group search.
+use crate::client::*;
+use async_gen::{self, AsyncIter};
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use super::{MonitorGroup, MonitorGroupsSearchRequest, Status};
+
+/// A monitor group that changed status between two polls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorTransition {
+    /// The ID of the monitor.
+    pub monitor_id: u64,
+    /// The name of the monitor.
+    pub monitor_name: String,
+    /// The name of the group, `*` for monitors without groups.
+    pub group: String,
+    /// The status at the previous poll, `Unknown` for groups that were not reported yet.
+    pub old_status: Status,
+    /// The status at the latest poll.
+    pub new_status: Status,
+    /// When the change was observed.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The status of every monitor group matching a query at one point in time.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MonitorSnapshot {
+    pub groups: HashMap<(u64, String), MonitorGroup>,
+}
+
+impl MonitorSnapshot {
+    pub fn from_groups(groups: Vec<MonitorGroup>) -> MonitorSnapshot {
+        MonitorSnapshot {
+            groups: groups
+                .into_iter()
+                .map(|group| ((group.monitor_id, group.group.clone()), group))
+                .collect(),
+        }
+    }
+
+    /// Fetch every page of the monitor group search.
+    pub async fn fetch(client: &Client, query: &str) -> DatadogResult<MonitorSnapshot> {
+        let mut groups: Vec<MonitorGroup> = vec![];
+        let mut page = 0;
+        loop {
+            let result = MonitorGroupsSearchRequest::default()
+                .query(query)
+                .page(page)
+                .per_page(100)
+                .send(client)
+                .await?;
+            groups.extend(result.groups);
+            if result.metadata.page + 1 >= result.metadata.page_count {
+                return Ok(MonitorSnapshot::from_groups(groups));
+            }
+            page += 1;
+        }
+    }
+
+    /// Groups whose status differs in `next`, including new groups not in `Unknown` status. Groups that disappeared are ignored.
+    pub fn transitions(&self, next: &MonitorSnapshot, timestamp: DateTime<Utc>) -> Vec<MonitorTransition> {
+        let mut transitions: Vec<MonitorTransition> = next
+            .groups
+            .iter()
+            .filter_map(|(key, group)| {
+                let old_status = self.groups.get(key).map(|old| old.status.clone()).unwrap_or_default();
+                match old_status == group.status {
+                    true => None,
+                    false => Some(MonitorTransition {
+                        monitor_id: group.monitor_id,
+                        monitor_name: group.monitor_name.clone(),
+                        group: group.group.clone(),
+                        old_status,
+                        new_status: group.status.clone(),
+                        timestamp,
+                    }),
+                }
+            })
+            .collect();
+        transitions.sort_by(|a, b| (a.monitor_id, &a.group).cmp(&(b.monitor_id, &b.group)));
+        transitions
+    }
+}
+
+/// `interval` scaled by a random factor between 0.8 and 1.2, so many watchers don't poll in lockstep.
+pub fn jittered(interval: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    let factor = 0.8 + (random % 1000) as f64 / 1000.0 * 0.4;
+    interval.mul_f64(factor)
+}
+
+/// Poll the monitor groups matching `query` about every `interval`, yielding every status change.
+///
+/// The first poll only records the current statuses. Failed polls are retried at the next interval, backing off up to ten
+/// intervals while they keep failing, and never end the stream.
+pub fn watch<'a>(client: &'a Client, query: &str, interval: Duration) -> impl Stream<Item = MonitorTransition> + 'a {
+    let query = query.to_string();
+    AsyncIter::from(async_gen::gen! {
+        let mut previous: Option<MonitorSnapshot> = None;
+        let mut failures: u32 = 0;
+        loop {
+            match MonitorSnapshot::fetch(client, &query).await {
+                Ok(snapshot) => {
+                    failures = 0;
+                    if let Some(previous) = &previous {
+                        for transition in previous.transitions(&snapshot, Utc::now()) {
+                            yield transition;
+                        }
+                    }
+                    previous = Some(snapshot);
+                }
+                Err(_) => failures = (failures + 1).min(10),
+            }
+            let wait = interval * failures.max(1);
+            tokio::time::sleep(jittered(wait)).await;
+        }
+    })
+}
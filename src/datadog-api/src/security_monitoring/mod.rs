@@ -56,6 +56,7 @@ pub enum Status {
   #[serde(rename="critical")]
   Critical,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for Status {
   fn default() -> Status {
     Status::Info
@@ -84,6 +85,7 @@ pub enum Action {
   #[serde(rename="suppress")]
   Suppress,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for Action {
   fn default() -> Action {
     Action::Require
@@ -122,6 +124,7 @@ pub enum DetectionMethod {
   #[serde(rename="anomaly_detection")]
   AnomalyDetection,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for DetectionMethod {
   fn default() -> DetectionMethod {
     DetectionMethod::Threshold
@@ -173,6 +176,7 @@ pub enum Aggregation {
   #[serde(rename="new_value")]
   NewValue,
 }
+#[allow(clippy::derivable_impls)]
 impl Default for Aggregation {
   fn default() -> Aggregation {
     Aggregation::Count
@@ -0,0 +1,416 @@
+//! Export monitors and dashboards as Terraform HCL for the [Datadog provider](https://registry.terraform.io/providers/DataDog/datadog/latest/docs),
+//! as `datadog_monitor` and `datadog_dashboard_json` resources with matching `import` blocks.
+//!
+//! Attributes left at the provider default are omitted so `terraform plan` comes out clean after importing.
+use crate::dashboards::{CreateDashboardRequest, CreateDashboardResponse};
+use crate::monitors::{GetMonitorResponse, MonitorOptions, ThresholdValue};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Monitors and dashboards to render as Terraform HCL.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TerraformExport {
+  /// Monitors, as returned by get/list monitors, rendered as `datadog_monitor` resources.
+  pub monitors: Vec<GetMonitorResponse>,
+  /// Dashboards, rendered as `datadog_dashboard_json` resources.
+  pub dashboards: Vec<CreateDashboardResponse>,
+  /// Whether to render `import` blocks (Terraform 1.5+) for every resource.
+  pub imports: bool,
+}
+impl TerraformExport {
+  pub fn monitor(mut self, monitor: GetMonitorResponse) -> TerraformExport {
+    self.monitors.push(monitor);
+    self
+  }
+  pub fn monitors(mut self, monitors: Vec<GetMonitorResponse>) -> TerraformExport {
+    self.monitors.extend(monitors);
+    self
+  }
+  pub fn dashboard(mut self, dashboard: CreateDashboardResponse) -> TerraformExport {
+    self.dashboards.push(dashboard);
+    self
+  }
+  pub fn dashboards(mut self, dashboards: Vec<CreateDashboardResponse>) -> TerraformExport {
+    self.dashboards.extend(dashboards);
+    self
+  }
+  pub fn imports(mut self, imports: bool) -> TerraformExport {
+    self.imports = imports;
+    self
+  }
+}
+
+impl TerraformExport {
+  /// Render every resource, and its import block if enabled, as HCL.
+  pub fn render(&self) -> String {
+    let mut names = ResourceNames::default();
+    let mut blocks: Vec<String> = vec![];
+    for monitor in &self.monitors {
+      let name = names.name("datadog_monitor", &monitor.name);
+      if self.imports {
+        blocks.push(import_block("datadog_monitor", &name, &monitor.id.to_string()));
+      }
+      blocks.push(Block::new(&format!("resource \"datadog_monitor\" \"{}\"", name), monitor_body(monitor)).to_string());
+    }
+    for dashboard in &self.dashboards {
+      let name = names.name("datadog_dashboard_json", &dashboard.title);
+      if self.imports {
+        blocks.push(import_block("datadog_dashboard_json", &name, &dashboard.id));
+      }
+      blocks.push(Block::new(&format!("resource \"datadog_dashboard_json\" \"{}\"", name), dashboard_body(dashboard)).to_string());
+    }
+    blocks.join("\n")
+  }
+}
+
+impl fmt::Display for TerraformExport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.render())
+  }
+}
+
+/// Unique Terraform resource names derived from object names, e.g. `High CPU on prod!` -> `high_cpu_on_prod`.
+#[derive(Debug, Default)]
+struct ResourceNames {
+  used: HashSet<String>,
+}
+
+impl ResourceNames {
+  fn name(&mut self, resource_type: &str, name: &str) -> String {
+    let mut base = String::new();
+    for c in name.to_lowercase().chars() {
+      match c.is_ascii_alphanumeric() {
+        true => base.push(c),
+        false if !base.is_empty() && !base.ends_with('_') => base.push('_'),
+        false => {}
+      }
+    }
+    let mut base = base.trim_end_matches('_').to_string();
+    if base.is_empty() || base.starts_with(|c: char| c.is_ascii_digit()) {
+      base = format!("{}_{}", resource_type.trim_start_matches("datadog_"), base).trim_end_matches('_').to_string();
+    }
+    let mut name = base.clone();
+    let mut i = 2;
+    while !self.used.insert(format!("{}.{}", resource_type, name)) {
+      name = format!("{}_{}", base, i);
+      i += 1;
+    }
+    name
+  }
+}
+
+fn import_block(resource_type: &str, name: &str, id: &str) -> String {
+  let body = vec![
+    Item::Attr("to".into(), format!("{}.{}", resource_type, name)),
+    Item::Attr("id".into(), string(id)),
+  ];
+  Block::new("import", body).to_string()
+}
+
+/// `datadog_monitor` arguments, mapped one-to-one from the monitor and its [`MonitorOptions`].
+fn monitor_body(monitor: &GetMonitorResponse) -> Vec<Item> {
+  let mut body = vec![
+    Item::Attr("name".into(), string(&monitor.name)),
+    Item::Attr("type".into(), string(&enum_value(&monitor._type))),
+    Item::Attr("query".into(), string(&monitor.query)),
+    Item::Attr("message".into(), string(&monitor.message)),
+  ];
+  if let Some(priority) = monitor.priority {
+    body.push(Item::Attr("priority".into(), string(&priority.to_string())));
+  }
+  if !monitor.tags.is_empty() {
+    let mut tags = monitor.tags.clone();
+    tags.sort();
+    body.push(Item::Attr("tags".into(), list(&tags)));
+  }
+  if let Some(restricted_roles) = monitor.restricted_roles.as_ref().filter(|roles| !roles.is_empty()) {
+    body.push(Item::Attr("restricted_roles".into(), list(restricted_roles)));
+  }
+  if let Some(options) = &monitor.options {
+    body.extend(options_body(options));
+  }
+  body
+}
+
+fn options_body(options: &MonitorOptions) -> Vec<Item> {
+  let mut body = vec![];
+  // Provider defaults, attributes equal to them are omitted.
+  let flags = [
+    ("enable_logs_sample", options.enable_logs_sample, false),
+    ("enable_samples", options.enable_samples, false),
+    ("groupby_simple_monitor", options.groupby_simple_monitor, false),
+    ("include_tags", options.include_tags, true),
+    ("notify_audit", options.notify_audit, false),
+    ("notify_no_data", options.notify_no_data, false),
+    ("require_full_window", options.require_full_window, true),
+  ];
+  for (name, value, default) in flags {
+    if let Some(value) = value.filter(|value| *value != default) {
+      body.push(Item::Attr(name.into(), value.to_string()));
+    }
+  }
+  let no_data_timeframe = match options.notify_no_data {
+    Some(true) => options.no_data_timeframe,
+    _ => None,
+  };
+  let numbers = [
+    ("evaluation_delay", options.evaluation_delay, 0),
+    ("new_group_delay", options.new_group_delay, 0),
+    ("new_host_delay", options.new_host_delay, 300),
+    ("no_data_timeframe", no_data_timeframe, 0),
+    ("renotify_interval", options.renotify_interval, 0),
+    ("renotify_occurrences", options.renotify_occurrences, 0),
+    ("timeout_h", options.timeout_h, 0),
+  ];
+  for (name, value, default) in numbers {
+    if let Some(value) = value.filter(|value| *value != default) {
+      body.push(Item::Attr(name.into(), value.to_string()));
+    }
+  }
+  let strings = [
+    ("escalation_message", options.escalation_message.clone()),
+    ("group_retention_duration", options.group_retention_duration.clone()),
+    ("notification_preset_name", options.notification_preset_name.as_ref().map(enum_value).filter(|value| value != "show_all")),
+    ("on_missing_data", options.on_missing_data.as_ref().map(enum_value).filter(|value| value != "default")),
+  ];
+  for (name, value) in strings {
+    if let Some(value) = value.filter(|value| !value.is_empty()) {
+      body.push(Item::Attr(name.into(), string(&value)));
+    }
+  }
+  if let Some(notify_by) = options.notify_by.as_ref().filter(|notify_by| !notify_by.is_empty()) {
+    body.push(Item::Attr("notify_by".into(), list(notify_by)));
+  }
+  if let Some(renotify_statuses) = options.renotify_statuses.as_ref().filter(|statuses| !statuses.is_empty()) {
+    body.push(Item::Attr("renotify_statuses".into(), list(renotify_statuses)));
+  }
+
+  if let Some(thresholds) = &options.thresholds {
+    let values = [
+      ("critical", &thresholds.critical),
+      ("critical_recovery", &thresholds.critical_recovery),
+      ("ok", &thresholds.ok),
+      ("unknown", &thresholds.unknown),
+      ("warning", &thresholds.warning),
+      ("warning_recovery", &thresholds.warning_recovery),
+    ];
+    let thresholds: Vec<Item> = values
+      .iter()
+      .filter_map(|(name, value)| value.as_ref().map(|value| Item::Attr(name.to_string(), number(value))))
+      .collect();
+    if !thresholds.is_empty() {
+      body.push(Item::Block(Block::new("monitor_thresholds", thresholds)));
+    }
+  }
+  if let Some(windows) = &options.threshold_windows {
+    let values = [("recovery_window", &windows.recovery_window), ("trigger_window", &windows.trigger_window)];
+    let windows: Vec<Item> = values
+      .iter()
+      .filter_map(|(name, value)| value.as_ref().map(|value| Item::Attr(name.to_string(), string(value))))
+      .collect();
+    if !windows.is_empty() {
+      body.push(Item::Block(Block::new("monitor_threshold_windows", windows)));
+    }
+  }
+  if let Some(scheduling_options) = &options.scheduling_options {
+    let mut scheduling = vec![];
+    if let Some(evaluation_window) = &scheduling_options.evaluation_window {
+      scheduling.push(Item::Block(Block::new("evaluation_window", value_body(&to_value(evaluation_window)))));
+    }
+    if let Some(custom_schedule) = &scheduling_options.custom_schedule {
+      let recurrences = custom_schedule
+        .recurrences
+        .iter()
+        .map(|recurrence| Item::Block(Block::new("recurrence", value_body(&to_value(recurrence)))))
+        .collect();
+      scheduling.push(Item::Block(Block::new("custom_schedule", recurrences)));
+    }
+    body.push(Item::Block(Block::new("scheduling_options", scheduling)));
+  }
+  if let Some(variables) = options.variables.as_ref().filter(|variables| !variables.is_empty()) {
+    let queries = variables
+      .iter()
+      .map(|variable| {
+        let value = to_value(variable);
+        // Event queries have a `compute`, cost queries don't.
+        let query_type = match value.get("compute") {
+          Some(_) => "event_query",
+          None => "cloud_cost_query",
+        };
+        Item::Block(Block::new(query_type, value_body(&value)))
+      })
+      .collect();
+    body.push(Item::Block(Block::new("variables", queries)));
+  }
+  body
+}
+
+/// `datadog_dashboard_json` arguments, the dashboard definition as JSON without widget IDs, which Datadog assigns.
+fn dashboard_body(dashboard: &CreateDashboardResponse) -> Vec<Item> {
+  let mut definition = to_value(&CreateDashboardRequest::from(dashboard.clone()));
+  strip_widget_ids(&mut definition);
+  if let Value::Object(fields) = &mut definition {
+    // `is_read_only` is deprecated in favour of `restricted_roles`, and `false` is the default.
+    if fields.get("is_read_only") == Some(&Value::Bool(false)) {
+      fields.remove("is_read_only");
+    }
+  }
+  let json = serde_json::to_string_pretty(&definition).unwrap_or_default();
+  vec![Item::Attr("dashboard".into(), heredoc(&json))]
+}
+
+fn strip_widget_ids(value: &mut Value) {
+  match value {
+    Value::Object(fields) => {
+      if let Some(Value::Array(widgets)) = fields.get_mut("widgets") {
+        for widget in widgets {
+          if let Value::Object(widget) = widget {
+            widget.remove("id");
+          }
+          strip_widget_ids(widget);
+        }
+      }
+      if let Some(definition) = fields.get_mut("definition") {
+        strip_widget_ids(definition);
+      }
+    }
+    Value::Array(values) => values.iter_mut().for_each(strip_widget_ids),
+    _ => {}
+  }
+}
+
+/// Arguments and nested blocks from a JSON object: objects become blocks, lists of objects repeated blocks.
+fn value_body(value: &Value) -> Vec<Item> {
+  let mut body = vec![];
+  if let Value::Object(fields) = value {
+    for (name, value) in fields {
+      match value {
+        Value::Null => {}
+        Value::Object(_) => body.push(Item::Block(Block::new(name, value_body(value)))),
+        Value::Array(values) if values.iter().all(Value::is_object) && !values.is_empty() => {
+          for value in values {
+            body.push(Item::Block(Block::new(name, value_body(value))));
+          }
+        }
+        Value::Array(values) => {
+          let values: Vec<String> = values.iter().map(scalar).collect();
+          body.push(Item::Attr(name.clone(), format!("[{}]", values.join(", "))));
+        }
+        value => body.push(Item::Attr(name.clone(), scalar(value))),
+      }
+    }
+  }
+  body
+}
+
+fn scalar(value: &Value) -> String {
+  match value {
+    Value::String(value) => string(value),
+    value => value.to_string(),
+  }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+  serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// The serialized name of an enum value, e.g. `metric alert`.
+fn enum_value<T: Serialize>(value: &T) -> String {
+  match to_value(value) {
+    Value::String(value) => value,
+    value => value.to_string(),
+  }
+}
+
+fn number(value: &ThresholdValue) -> String {
+  value.to_string()
+}
+
+/// A quoted HCL string, escaping template sequences so `{{value}}`-style messages stay literal.
+fn string(value: &str) -> String {
+  let mut quoted = String::from("\"");
+  for c in value.chars() {
+    match c {
+      '"' => quoted.push_str("\\\""),
+      '\\' => quoted.push_str("\\\\"),
+      '\n' => quoted.push_str("\\n"),
+      '\r' => quoted.push_str("\\r"),
+      '\t' => quoted.push_str("\\t"),
+      c => quoted.push(c),
+    }
+  }
+  quoted.push('"');
+  quoted.replace("${", "$${").replace("%{", "%%{")
+}
+
+fn list(values: &[String]) -> String {
+  let values: Vec<String> = values.iter().map(|value| string(value)).collect();
+  format!("[{}]", values.join(", "))
+}
+
+fn heredoc(value: &str) -> String {
+  format!("<<-EOT\n{}\nEOT", value.replace("${", "$${").replace("%{", "%%{"))
+}
+
+/// An HCL body item.
+#[derive(Debug, Clone, PartialEq)]
+enum Item {
+  Attr(String, String),
+  Block(Block),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Block {
+  header: String,
+  body: Vec<Item>,
+}
+
+impl Block {
+  fn new(header: &str, body: Vec<Item>) -> Block {
+    Block { header: header.into(), body }
+  }
+
+  /// Write the block, aligning the `=` of consecutive arguments like `terraform fmt`.
+  fn write(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+    let pad = "  ".repeat(indent);
+    writeln!(f, "{}{} {{", pad, self.header)?;
+    let mut i = 0;
+    while i < self.body.len() {
+      match &self.body[i] {
+        Item::Block(block) => {
+          block.write(f, indent + 1)?;
+          i += 1;
+        }
+        Item::Attr(_, _) => {
+          let end = self.body[i..]
+            .iter()
+            .position(|item| matches!(item, Item::Block(_)))
+            .map_or(self.body.len(), |position| i + position);
+          let width = self.body[i..end]
+            .iter()
+            .map(|item| match item {
+              Item::Attr(name, _) => name.len(),
+              Item::Block(_) => 0,
+            })
+            .max()
+            .unwrap_or(0);
+          for item in &self.body[i..end] {
+            if let Item::Attr(name, value) = item {
+              writeln!(f, "{}  {:width$} = {}", pad, name, value, width = width)?;
+            }
+          }
+          i = end;
+        }
+      }
+    }
+    writeln!(f, "{}}}", pad)
+  }
+}
+
+impl fmt::Display for Block {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.write(f, 0)
+  }
+}
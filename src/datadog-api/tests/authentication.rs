@@ -7,5 +7,5 @@ fn validates_an_api_key() {
     let client = Client::default();
     let req = ValidateRequest {};
     let res = block_on(req.send(&client)).expect("API call failed");
-    assert_eq!(true, res.valid);
+    assert!(res.valid);
 }
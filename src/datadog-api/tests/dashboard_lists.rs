@@ -7,7 +7,7 @@ fn dashboard_list_get_items() {
     let client = Client::default();
     let req = GetDashboardListItemsRequest::default()
         .dashboard_list_id(242202);
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.dashboards.len());
 }
 
@@ -20,7 +20,7 @@ fn dashboard_list_delete_items() {
             id: "bkt-hmh-j8u".to_string(),
             _type: "custom_timeboard".to_string(),
         }));
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.deleted_dashboards_from_list.len());
 }
 
@@ -33,6 +33,6 @@ fn dashboard_list_add_items() {
             id: "bkt-hmh-j8u".to_string(),
             _type: "custom_timeboard".to_string(),
         }));
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.added_dashboards_to_list.len());
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use datadog_api::Client;
 use datadog_api::dashboards::*;
 use tokio_test::block_on;
+use futures_util::StreamExt;
 
 /// Cretes a dashboard with the minimal amount of settings supported by DD
 #[test]
@@ -9,7 +10,7 @@ fn test_create_dashboard_empty() {
     let req = CreateDashboardRequest::default()
         .title("datadog-api-rs: test_create_dashboard_empty")
         .layout_type(LayoutType::Ordered);
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
 }
 
@@ -21,7 +22,7 @@ fn test_update_dashboard_empty() {
         .dashboard_id("v2g-k58-bhe")
         .title("datadog-api-rs: test_update_dashboard_empty")
         .layout_type(LayoutType::Ordered);
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
 }
 
@@ -41,6 +42,7 @@ fn test_update_dashboard_complete() {
             tick_pos: Some("50%".to_string()),
             tick_edge: Some(TickEdge::Left),
             has_padding: Some(true),
+            ..Default::default()
         })),
         layout: Some(WidgetLayout {
             is_column_break: None,
@@ -67,21 +69,24 @@ fn test_update_dashboard_complete() {
                         Formula {
                             alias: None,
                             formula: "query1".to_string(),
+                            ..Default::default()
                         }
                     )),
                     response_format: Some(ResponseFormat::Scalar),
                     queries: Some(vec!(
-                        Query::Option1(QueryOption1 {
+                        Query::Metrics(MetricsQuery {
                             query: "sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{$team}".to_string(),
-                            data_source: "metrics".to_string(),
+                            data_source: MetricsDataSource::Metrics,
                             name: "query1".to_string(),
                             aggregator: Some(MetricAggregator::Last),
                         })
-                    ))
+                    )),
+                    ..Default::default()
                 }
             )),
             autoscale: Some(false),
-            precision: Some(0)
+            precision: Some(0),
+            extra: Default::default(),
         })),
         layout: Some(WidgetLayout {
             is_column_break: None,
@@ -99,6 +104,412 @@ fn test_update_dashboard_complete() {
             note,
             queryvalue,
         ));
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
 }
+
+/// Creates, reads back, deletes and restores a dashboard
+#[test]
+fn test_dashboard_lifecycle() {
+    let client = Client::default();
+    let req = CreateDashboardRequest::default()
+        .title("datadog-api-rs: test_dashboard_lifecycle")
+        .layout_type(LayoutType::Ordered);
+    let created = block_on(req.send(&client)).expect("API call failed");
+
+    let req = GetDashboardRequest::default().dashboard_id(&created.id);
+    let res = block_on(req.send(&client)).expect("API call failed");
+    assert_eq!(created.title, res.title);
+
+    let req = DeleteDashboardRequest::default().dashboard_id(&created.id);
+    let res = block_on(req.send(&client)).expect("API call failed");
+    assert_eq!(created.id, res.deleted_dashboard_id);
+
+    let req = RestoreDashboardsRequest::default().dashboard_id(&created.id);
+    let _res = block_on(req.send(&client)).expect("API call failed");
+
+    let req = DeleteDashboardsRequest::default().dashboard_id(&created.id);
+    let _res = block_on(req.send(&client)).expect("API call failed");
+}
+
+/// Iterable list of dashboards
+#[test]
+fn test_list_dashboards_iter() {
+    block_on(async {
+        let client = Client::default();
+        let req = ListDashboardsRequest::default().filter_shared(false).count(10);
+        let dashboards: Vec<DashboardSummary> = req.iter(&client).take(25).collect().await;
+        assert!(dashboards.iter().all(|dashboard| !dashboard.id.is_empty()));
+    });
+}
+
+#[test]
+fn test_dashboard_bulk_action_body() {
+    let req = DeleteDashboardsRequest::default()
+        .dashboard_id("abc-def-ghi")
+        .dashboard_id("123-456-789");
+    assert_eq!(
+        r#"{"data":[{"id":"abc-def-ghi","type":"dashboard"},{"id":"123-456-789","type":"dashboard"}]}"#,
+        serde_json::to_string(&req).unwrap()
+    );
+    let res: ListDashboardsResponse = serde_json::from_str(r#"{"dashboards":[{"id":"abc-def-ghi","title":"Overview","layout_type":"free","is_read_only":false}]}"#).unwrap();
+    assert_eq!(LayoutType::Free, res.dashboards[0].layout_type);
+}
+
+/// Parses `json` into `expected`, and serializes it back to the same JSON
+fn assert_round_trip(json: &str, expected: WidgetDefinition) {
+    let definition: WidgetDefinition = serde_json::from_str(json).unwrap();
+    assert_eq!(expected, definition);
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(value, serde_json::to_value(&definition).unwrap());
+}
+
+#[test]
+fn test_timeseries_widget() {
+    let json = r#"{"type":"timeseries","requests":[{"formulas":[{"formula":"query1"}],"queries":[{"data_source":"metrics","name":"query1","query":"avg:system.cpu.user{*} by {host}"}],"response_format":"timeseries","display_type":"bars","metadata":[{"alias_name":"CPU","expression":"query1"}],"on_right_yaxis":false,"style":{"line_type":"dashed","line_width":"thin","palette":"warm"}}],"yaxis":{"include_zero":true,"max":"100","scale":"linear"},"markers":[{"display_type":"error dashed","label":"High","value":"y = 90"}],"events":[{"q":"sources:deploy"}],"legend_layout":"vertical","legend_columns":["avg","max"],"show_legend":true,"time":{"live_span":"4h"},"title":"CPU"}"#;
+    let built = TimeSeries::default()
+        .title("CPU")
+        .request(
+            TimeSeriesRequest::metrics("avg:system.cpu.user{*} by {host}")
+                .display_type(DisplayType::Bars)
+                .alias("query1", "CPU")
+                .on_right_yaxis(false)
+                .style(RequestStyle::default().line_type(LineType::Dashed).line_width(LineWidth::Thin).palette("warm")),
+        )
+        .yaxis(Axis::default().include_zero(true).max("100").scale("linear"))
+        .marker(Marker::default().display_type("error dashed").label("High").value("y = 90"))
+        .event("sources:deploy")
+        .legend_layout(LegendLayout::Vertical)
+        .legend_columns(vec![LegendColumn::Average, LegendColumn::Maximum])
+        .show_legend(true)
+        .live_span("4h");
+    assert_round_trip(json, WidgetDefinition::TimeSeries(built));
+}
+
+#[test]
+fn test_toplist_table_change_widgets() {
+    let json = r##"{"type":"toplist","requests":[{"conditional_formats":[{"comparator":">","palette":"white_on_red","value":90.0},{"comparator":"<=","custom_bg_color":"#00ff00","palette":"custom_bg","value":10.0}],"formulas":[{"formula":"query1","limit":{"count":10,"order":"desc"}}],"queries":[{"data_source":"metrics","name":"query1","query":"avg:system.cpu.user{*} by {host}"}],"response_format":"scalar"}],"title":"Busiest hosts"}"##;
+    let built = TopList::default()
+        .title("Busiest hosts")
+        .request(
+            TopListRequest::metrics("avg:system.cpu.user{*} by {host}", 10)
+                .conditional_format(ConditionalFormat::when(WidgetComparator::GreaterThan, 90.0, ConditionalFormatPalette::WhiteOnRed))
+                .conditional_format(ConditionalFormat::when(WidgetComparator::LessThanOrEqual, 10.0, ConditionalFormatPalette::Green).custom_bg_color("#00ff00")),
+        );
+    assert_round_trip(json, WidgetDefinition::TopList(built));
+
+    let json = r#"{"type":"query_table","has_search_bar":"never","requests":[{"formulas":[{"alias":"CPU","cell_display_mode":"bar","conditional_formats":[{"comparator":">","image_url":"https://example.com/fire.png","palette":"custom_image","value":95.0}],"formula":"query1","limit":{"count":25,"order":"desc"}}],"queries":[{"data_source":"metrics","name":"query1","query":"avg:system.cpu.user{*} by {host}"}],"response_format":"scalar"}],"title":"Hosts"}"#;
+    let formula = Formula::from("query1")
+        .alias("CPU")
+        .cell_display_mode(CellDisplayMode::Bar)
+        .conditional_format(ConditionalFormat::when(WidgetComparator::GreaterThan, 95.0, ConditionalFormatPalette::Red).image_url("https://example.com/fire.png"))
+        .limit(25, SortOrder::Descending);
+    let built = Table::default()
+        .title("Hosts")
+        .has_search_bar(HasSearchBar::Never)
+        .request(TableRequest {
+            request: Request::default()
+                .query(Query::metrics("query1", "avg:system.cpu.user{*} by {host}"))
+                .formula(formula)
+                .response_format(ResponseFormat::Scalar),
+            ..Default::default()
+        });
+    assert_round_trip(json, WidgetDefinition::Table(built));
+
+    let json = r#"{"type":"change","requests":[{"formulas":[{"formula":"query1"}],"queries":[{"data_source":"metrics","name":"query1","query":"sum:app.requests{*} by {service}"}],"response_format":"scalar","change_type":"relative","compare_to":"week_before","increase_good":true,"order_by":"change","order_dir":"desc","show_present":true}],"title":"Requests"}"#;
+    let built = Change::default().title("Requests").request(
+        ChangeRequest::metrics("sum:app.requests{*} by {service}", CompareTo::WeekBefore)
+            .change_type(ChangeType::Relative)
+            .increase_good(true)
+            .order(ChangeOrderBy::Change, SortOrder::Descending)
+            .show_present(true),
+    );
+    assert_round_trip(json, WidgetDefinition::Change(built));
+}
+
+#[test]
+fn test_group_widget() {
+    let json = r#"{"definition":{"type":"group","background_color":"vivid_blue","banner_img":"https://example.com/banner.png","layout_type":"ordered","show_title":true,"title":"Hosts","widgets":[{"definition":{"type":"group","layout_type":"ordered","title":"CPU","widgets":[{"definition":{"type":"note","content":"Nested"},"id":2,"layout":{"x":0,"y":0,"width":4,"height":2}}]},"id":1}]},"id":0}"#;
+    let widget: Widget = serde_json::from_str(json).unwrap();
+    let inner = Group::default().title("CPU").widget(Widget {
+        id: Some(2),
+        definition: Some(WidgetDefinition::NotesAndLinks(NotesAndLinks {
+            content: "Nested".to_string(),
+            ..Default::default()
+        })),
+        layout: Some(WidgetLayout {
+            is_column_break: None,
+            x: 0,
+            y: 0,
+            width: 4,
+            height: 2,
+        }),
+    });
+    let group = Group::default()
+        .title("Hosts")
+        .background_color("vivid_blue")
+        .banner_img("https://example.com/banner.png")
+        .show_title(true)
+        .widget(Widget {
+            id: Some(1),
+            ..Widget::from(WidgetDefinition::Group(inner))
+        });
+    assert_eq!(Widget { id: Some(0), ..Widget::from(WidgetDefinition::Group(group)) }, widget);
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(value, serde_json::to_value(&widget).unwrap());
+}
+
+#[test]
+fn test_heatmap_distribution_scatterplot_widgets() {
+    let json = r#"{"type":"heatmap","requests":[{"formulas":[{"formula":"query1"}],"queries":[{"data_source":"metrics","name":"query1","query":"avg:system.cpu.user{*} by {host}"}],"response_format":"timeseries","style":{"palette":"dog_classic"}}],"show_legend":false,"title":"CPU","yaxis":{"include_zero":true,"scale":"log"}}"#;
+    let built = HeatMap::default()
+        .title("CPU")
+        .request(HeatMapRequest::metrics("avg:system.cpu.user{*} by {host}").palette("dog_classic"))
+        .show_legend(false)
+        .yaxis(Axis::default().include_zero(true).scale("log"));
+    assert_round_trip(json, WidgetDefinition::HeatMap(built));
+
+    let json = r#"{"type":"distribution","markers":[{"display_type":"percentile","value":"p99"}],"requests":[{"query":{"data_source":"metrics","name":"query1","query":"avg:trace.http.request{service:web}"},"request_type":"histogram"},{"apm_stats_query":{"env":"prod","name":"http.request","primary_tag":"datacenter:*","row_type":"resource","service":"web"}}],"title":"Latency","xaxis":{"max":"p99","min":"auto"}}"#;
+    let built = Distribution::default()
+        .title("Latency")
+        .metrics("avg:trace.http.request{service:web}")
+        .request(DistributionRequest::apm_stats(ApmStatsQuery {
+            env: "prod".into(),
+            name: "http.request".into(),
+            primary_tag: "datacenter:*".into(),
+            row_type: ApmStatsRowType::Resource,
+            service: "web".into(),
+            ..Default::default()
+        }))
+        .marker(Marker::default().display_type("percentile").value("p99"))
+        .xaxis(Axis::default().max("p99").min("auto"));
+    assert_round_trip(json, WidgetDefinition::Distribution(built));
+
+    let json = r#"{"type":"scatterplot","color_by_groups":["service"],"requests":{"table":{"formulas":[{"dimension":"x","formula":"query1"},{"dimension":"y","formula":"query2"}],"queries":[{"aggregator":"avg","data_source":"metrics","name":"query1","query":"avg:system.cpu.user{*} by {host}"},{"aggregator":"avg","data_source":"metrics","name":"query2","query":"avg:system.mem.used{*} by {host}"}],"response_format":"scalar"}},"title":"CPU vs memory"}"#;
+    let built = ScatterPlot::default()
+        .title("CPU vs memory")
+        .table(ScatterPlotTableRequest::metrics("avg:system.cpu.user{*} by {host}", "avg:system.mem.used{*} by {host}"))
+        .color_by_groups(vec!["service".into()]);
+    assert_round_trip(json, WidgetDefinition::ScatterPlot(built));
+
+    let json = r#"{"type":"scatterplot","requests":{"x":{"aggregator":"avg","q":"avg:system.cpu.user{*} by {host}"},"y":{"aggregator":"max","q":"avg:system.mem.used{*} by {host}"}}}"#;
+    let built = ScatterPlot::default()
+        .x(ScatterPlotRequest::default().aggregator(RequestAggregator::Average).q("avg:system.cpu.user{*} by {host}"))
+        .y(ScatterPlotRequest::default().aggregator(RequestAggregator::Maximum).q("avg:system.mem.used{*} by {host}"));
+    assert_round_trip(json, WidgetDefinition::ScatterPlot(built));
+}
+
+#[test]
+fn test_hostmap_geomap_servicemap_widgets() {
+    let json = r#"{"type":"hostmap","group":["availability-zone"],"no_group_hosts":true,"no_metric_hosts":false,"node_type":"container","requests":{"fill":{"q":"avg:system.cpu.user{*} by {host}"},"size":{"q":"avg:system.mem.used{*} by {host}"}},"scope":["region:eu-west-1"],"style":{"fill_max":"100","fill_min":"0","palette":"green_to_orange","palette_flip":true},"title":"eu-west-1"}"#;
+    let built = Hostmap::default()
+        .title("eu-west-1")
+        .fill("avg:system.cpu.user{*} by {host}")
+        .size("avg:system.mem.used{*} by {host}")
+        .group(vec!["availability-zone".into()])
+        .scope(vec!["region:eu-west-1".into()])
+        .no_group_hosts(true)
+        .no_metric_hosts(false)
+        .node_type(NodeType::Container)
+        .style(HostmapStyle::default().fill_max("100").fill_min("0").palette("green_to_orange").palette_flip(true));
+    assert_round_trip(json, WidgetDefinition::Hostmap(built));
+
+    let json = r#"{"type":"geomap","requests":[{"formulas":[{"formula":"query1"}],"queries":[{"data_source":"metrics","name":"query1","query":"sum:app.visits{*} by {country_iso_code}"}],"response_format":"scalar"}],"style":{"palette":"hostmap_blues","palette_flip":false},"title":"Visits","view":{"focus":"FR"}}"#;
+    let built = Geomap::default()
+        .title("Visits")
+        .metrics("sum:app.visits{*} by {country_iso_code}")
+        .focus("FR");
+    assert_round_trip(json, WidgetDefinition::Geomap(built));
+
+    let style: GeomapStyle = serde_json::from_str(r#"{"palette_flip":true}"#).unwrap();
+    assert_eq!("hostmap_blues", style.palette);
+    assert!(style.palette_flip);
+
+    let json = r#"{"type":"servicemap","filters":["env:prod","datacenter:us1"],"service":"web","title":"web"}"#;
+    let built = ServiceMap::default().title("web").service("web").env("prod").filter("datacenter:us1");
+    assert_round_trip(json, WidgetDefinition::ServiceMap(built));
+}
+
+#[test]
+fn test_slo_and_monitor_widgets() {
+    let json = r#"{"type":"slo","global_time_target":"99.9","show_error_budget":true,"slo_id":"56789","time_windows":["7d","30d","global_time"],"title":"Checkout","view_mode":"both","view_type":"detail"}"#;
+    let built = ServiceLevelObjective::default()
+        .title("Checkout")
+        .slo_id("56789")
+        .time_windows(vec![TimeWindow::SevenDays, TimeWindow::ThirtyDays, TimeWindow::GlobalTime])
+        .global_time_target("99.9")
+        .show_error_budget(true)
+        .view_mode(SloViewMode::Both);
+    assert_round_trip(json, WidgetDefinition::ServiceLevelObjective(built));
+
+    let json = r#"{"type":"manage_status","color_preference":"text","display_format":"countsAndList","hide_zero_counts":true,"query":"id:12345 OR id:67890","show_last_triggered":true,"sort":"status,asc","summary_type":"groups","title":"Monitors"}"#;
+    let built = MonitorSummary::default()
+        .title("Monitors")
+        .monitor_ids(&[12345, 67890])
+        .sort("status,asc")
+        .display_format(MonitorSummaryDisplayFormat::CountsAndList)
+        .summary_type(SummaryType::Groups)
+        .color_preference(ColorPreference::Text)
+        .hide_zero_counts(true)
+        .show_last_triggered(true);
+    assert_round_trip(json, WidgetDefinition::MonitorSummary(built));
+
+    let monitor = datadog_api::monitors::GetMonitorResponse {
+        id: 12345,
+        name: "High CPU".into(),
+        ..Default::default()
+    };
+    let json = r#"{"type":"alert_graph","alert_id":"12345","title":"High CPU","viz_type":"toplist"}"#;
+    let built = AlertGraph::from(&monitor).viz_type(VizType::Toplist);
+    assert_round_trip(json, WidgetDefinition::AlertGraph(built));
+
+    let json = r#"{"type":"alert_value","alert_id":"12345","precision":2,"text_align":"center","title":"High CPU","unit":"%"}"#;
+    let built = AlertValue::from(&monitor).precision(2).text_align(TextAlign::Center).unit("%");
+    assert_round_trip(json, WidgetDefinition::AlertValue(built));
+
+    let json = r#"{"type":"check_status","check":"datadog.agent.up","group_by":["availability-zone"],"grouping":"cluster","tags":["env:prod"],"title":"Agents"}"#;
+    let built = CheckStatus::default()
+        .title("Agents")
+        .check("datadog.agent.up")
+        .cluster(vec!["availability-zone".into()], vec!["env:prod".into()]);
+    assert_round_trip(json, WidgetDefinition::CheckStatus(built));
+}
+
+#[test]
+fn test_content_widgets() {
+    let json = r#"{"type":"log_stream","columns":["host","service"],"indexes":["main"],"message_display":"expanded-md","query":"service:web status:error","show_date_column":true,"show_message_column":true,"sort":{"column":"time","order":"desc"},"title":"Errors"}"#;
+    let built = LogStream::default()
+        .title("Errors")
+        .query("service:web status:error")
+        .indexes(vec!["main".into()])
+        .columns(vec!["host".into(), "service".into()])
+        .sort("time", SortOrder::Descending)
+        .message_display(MessageDisplay::ExpandedMedium)
+        .show_date_column(true)
+        .show_message_column(true);
+    assert_round_trip(json, WidgetDefinition::LogStream(built));
+
+    let definitions = vec![
+        (
+            r#"{"type":"event_stream","event_size":"l","query":"sources:deploy","tags_execution":"and","title":"Deploys"}"#,
+            WidgetDefinition::EventStream(EventStream::default().title("Deploys").query("sources:deploy").tags_execution("and").event_size(EventSize::Large)),
+        ),
+        (
+            r#"{"type":"event_timeline","query":"sources:deploy","time":{"live_span":"1d"}}"#,
+            WidgetDefinition::EventTimeline(EventTimeline::default().query("sources:deploy").live_span("1d")),
+        ),
+        (
+            r#"{"type":"iframe","url":"https://example.com"}"#,
+            WidgetDefinition::Iframe(Iframe::default().url("https://example.com")),
+        ),
+        (
+            r#"{"type":"image","has_background":false,"margin":"sm","sizing":"cover","url":"https://example.com/logo.png"}"#,
+            WidgetDefinition::Image(Image::default().url("https://example.com/logo.png").sizing(ImageSizing::Cover).margin(Margin::Small).has_background(false)),
+        ),
+        (
+            r##"{"type":"free_text","color":"#4d4d4d","font_size":"36","text":"Checkout","text_align":"center"}"##,
+            WidgetDefinition::FreeText(FreeText::default().text("Checkout").color("#4d4d4d").font_size("36").text_align(TextAlign::Center)),
+        ),
+        (
+            r#"{"type":"trace_service","env":"prod","service":"web","size_format":"large","span_name":"http.request"}"#,
+            WidgetDefinition::ServiceSummary(ServiceSummary::default().service("web", "prod", "http.request").size_format(SizeFormat::Large)),
+        ),
+    ];
+    for (json, built) in definitions {
+        assert_round_trip(json, built);
+    }
+}
+
+#[test]
+fn test_unknown_widget_round_trip() {
+    let json = r#"{"layout_type":"ordered","title":"Mixed","widgets":[{"definition":{"type":"sunburst","requests":[{"queries":[{"data_source":"metrics","name":"query1","query":"sum:app.requests{*} by {service}"}],"response_format":"scalar"}],"legend":{"type":"automatic"}},"id":1},{"definition":{"type":"split_group","source_widget_definition":{"type":"timeseries","requests":[]},"split_config":{"limit":24}},"id":2},{"definition":{"type":"free_text","text":"Known"},"id":3}]}"#;
+    let dashboard: UpdateDashboardResponse = serde_json::from_str(json).unwrap();
+    match &dashboard.widgets[0].definition {
+        Some(WidgetDefinition::Unknown { _type, definition }) => {
+            assert_eq!("sunburst", _type);
+            assert_eq!("automatic", definition["legend"]["type"]);
+        }
+        other => panic!("unexpected definition: {:?}", other),
+    }
+    assert_eq!(Some(WidgetDefinition::FreeText(FreeText::default().text("Known"))), dashboard.widgets[2].definition);
+
+    let request = CreateDashboardRequest::default().title("Mixed").widgets(dashboard.widgets);
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(value, serde_json::to_value(&request).unwrap());
+}
+
+#[test]
+fn test_known_widget_extra_fields_round_trip() {
+    let json = r#"{"type":"note","content":"x","unexpected":1}"#;
+    let mut note = NotesAndLinks { content: "x".to_string(), ..Default::default() };
+    note.extra.insert("unexpected".to_string(), serde_json::json!(1));
+    assert_round_trip(json, WidgetDefinition::NotesAndLinks(note));
+
+    let json = r#"{"type":"timeseries","requests":[{"formulas":[{"formula":"query1","style":{"palette":"warm"}}],"queries":[{"data_source":"metrics","name":"query1","query":"avg:system.cpu.user{*}"}],"response_format":"timeseries","display_type":"line","sort":{"count":10}}]}"#;
+    let definition: WidgetDefinition = serde_json::from_str(json).unwrap();
+    match &definition {
+        WidgetDefinition::TimeSeries(timeseries) => {
+            let request = &timeseries.requests[0].request;
+            assert_eq!(serde_json::json!({"count": 10}), request.extra["sort"]);
+            assert_eq!(serde_json::json!({"palette": "warm"}), request.formulas.as_ref().unwrap()[0].extra["style"]);
+        }
+        other => panic!("unexpected definition: {:?}", other),
+    }
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(value, serde_json::to_value(&definition).unwrap());
+}
+
+#[test]
+fn test_query_variants_round_trip() {
+    let json = r#"{"type":"timeseries","requests":[{"formulas":[{"formula":"query1 / query2"}],"queries":[{"aggregator":"avg","data_source":"metrics","name":"query0","query":"avg:system.load.1{*}"},{"compute":{"aggregation":"count"},"data_source":"logs","group_by":[{"facet":"service","limit":10}],"indexes":["main"],"name":"query1","search":{"query":"status:error"}},{"compute":{"aggregation":"avg","metric":"@duration"},"data_source":"spans","name":"query2","search":{"query":"env:prod"}},{"data_source":"process","is_normalized_cpu":false,"limit":10,"metric":"process.stat.cpu.total_pct","name":"query3","sort":"desc","tag_filters":["env:prod"],"text_filter":"nginx"},{"data_source":"apm_dependency_stats","env":"prod","is_upstream":true,"name":"query4","operation_name":"rack.request","resource_name":"GET /","service":"web","stat":"avg_duration"},{"data_source":"apm_resource_stats","env":"prod","group_by":["resource_name"],"name":"query5","service":"web","stat":"hits"},{"data_source":"slo","group_mode":"overall","measure":"slo_status","name":"query6","slo_id":"abc123","slo_query_type":"metric"},{"aggregator":"sum","data_source":"cloud_cost","name":"query7","query":"sum:aws.cost.amortized{*} by {aws_product}"}],"response_format":"timeseries"},{"q":"avg:system.cpu.user{*}"},{"log_query":{"compute":{"aggregation":"count"},"group_by":[{"facet":"host","limit":5}],"index":"*","search":{"query":"service:web"}}},{"apm_query":{"compute":{"aggregation":"avg","facet":"@duration"},"index":"trace-search","search":{"query":"env:prod"}}},{"process_query":{"filter_by":["env:prod"],"limit":10,"metric":"process.stat.cpu.total_pct","search_by":"nginx"}}]}"#;
+    let request = |request: Request| TimeSeriesRequest {
+        request,
+        ..Default::default()
+    };
+    let timeseries = TimeSeries::default()
+        .request(request(
+            Request::default()
+                .query(MetricsQuery::new("query0", "avg:system.load.1{*}").aggregator(MetricAggregator::Average))
+                .query(EventsQuery::logs("query1").search("status:error").group_by("service", 10).index("main"))
+                .query(EventsQuery::spans("query2").search("env:prod").compute("avg", Some("@duration")))
+                .query(ProcessQuery {
+                    is_normalized_cpu: Some(false),
+                    ..ProcessQuery::new("query3", "process.stat.cpu.total_pct")
+                        .text_filter("nginx")
+                        .tag_filter("env:prod")
+                        .limit(10, SortOrder::Descending)
+                })
+                .query(ApmDependencyStatsQuery::new("query4", "prod", "web", "rack.request", "GET /", "avg_duration").is_upstream(true))
+                .query(ApmResourceStatsQuery::new("query5", "prod", "web", "hits").group_by(vec!["resource_name".to_string()]))
+                .query(SloQuery::new("query6", "abc123", "slo_status").group_mode("overall").slo_query_type("metric"))
+                .query(CloudCostQuery::new("query7", "sum:aws.cost.amortized{*} by {aws_product}").aggregator(RequestAggregator::Sum))
+                .formula("query1 / query2")
+                .response_format(ResponseFormat::Timeseries),
+        ))
+        .request(request(Request::default().q("avg:system.cpu.user{*}")))
+        .request(request(
+            Request::default().log_query(LogQueryDefinition::default().search("service:web").compute("count", None).group_by("host", 5).index("*")),
+        ))
+        .request(request(
+            Request::default().apm_query(LogQueryDefinition::default().search("env:prod").compute("avg", Some("@duration")).index("trace-search")),
+        ))
+        .request(request(
+            Request::default().process_query(ProcessQueryDefinition {
+                filter_by: Some(vec!["env:prod".to_string()]),
+                limit: Some(10),
+                metric: "process.stat.cpu.total_pct".to_string(),
+                search_by: Some("nginx".to_string()),
+            }),
+        ));
+    let definition = WidgetDefinition::TimeSeries(timeseries);
+    assert_round_trip(json, definition.clone());
+    match &definition {
+        WidgetDefinition::TimeSeries(timeseries) => {
+            let queries = timeseries.requests[0].request.queries.as_ref().unwrap();
+            assert!(matches!(queries[1], Query::Events(EventsQuery { data_source: EventsDataSource::Logs, .. })));
+            assert!(matches!(queries[6], Query::Slo(_)));
+            assert!(matches!(queries[7], Query::CloudCost(_)));
+        }
+        other => panic!("unexpected definition: {:?}", other),
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{Duration, NaiveDate};
+use datadog_api::Client;
+use datadog_api::downtimes::*;
+use datadog_api::monitors::MuteMonitorRequest;
+use futures_util::StreamExt;
+use tokio_test::block_on;
+
+/// Mutes a monitor for the next 30 minutes, then cancels the downtime
+#[test]
+fn test_create_and_cancel_downtime() {
+    let client = Client::default();
+    let req = CreateDowntimeRequest::default()
+        .monitor_id(55679649)
+        .scope("env:staging")
+        .message("Release in progress")
+        .mute_for(Duration::minutes(30));
+    let res = block_on(req.send(&client)).expect("API call failed");
+    assert_eq!("env:staging", res.data.attributes.scope);
+
+    let req = CancelDowntimeRequest::default()
+        .downtime_id(&res.data.id);
+    let _res = block_on(req.send(&client)).expect("API call failed");
+}
+
+/// Iterable list of downtimes
+#[test]
+fn test_list_downtimes_iter() {
+    block_on(async {
+        let client = Client::default();
+        let req = ListDowntimesRequest::default()
+            .current_only(true)
+            .page_limit(10);
+        let v: Vec<Downtime> = req.iter(&client).collect().await;
+        for downtime in &v {
+            assert!(!downtime.id.is_empty());
+            assert_eq!(downtime._type, "downtime");
+        }
+    });
+}
+
+#[test]
+fn test_recurring_downtime_request() {
+    let recurrence = DowntimeRecurrence::default()
+        .rrule("FREQ=WEEKLY;BYDAY=MO")
+        .duration(Duration::hours(2))
+        .start(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(22, 0, 0).unwrap());
+    let req = CreateDowntimeRequest::default()
+        .monitor_tags(vec!["service:postgres".into()])
+        .scope("env:prod")
+        .schedule(DowntimeSchedule::recurring(vec![recurrence], "Europe/Amsterdam"))
+        .notify_end_states(vec![NotifyEndState::Alert, NotifyEndState::NoData])
+        .notify_end_types(vec![NotifyEndType::Expired])
+        .mute_first_recovery_notification(true);
+    assert_eq!(
+        serde_json::json!({
+            "data": {
+                "type": "downtime",
+                "attributes": {
+                    "monitor_identifier": {"monitor_tags": ["service:postgres"]},
+                    "mute_first_recovery_notification": true,
+                    "notify_end_states": ["alert", "no data"],
+                    "notify_end_types": ["expired"],
+                    "schedule": {
+                        "recurrences": [{"duration": "2h", "rrule": "FREQ=WEEKLY;BYDAY=MO", "start": "2024-01-01T22:00"}],
+                        "timezone": "Europe/Amsterdam"
+                    },
+                    "scope": "env:prod"
+                }
+            }
+        }),
+        serde_json::to_value(&req).unwrap()
+    );
+
+    assert_eq!("90m", format_duration(Duration::minutes(90)));
+    assert_eq!("1d", format_duration(Duration::hours(24)));
+    assert_eq!("2w", format_duration(Duration::days(14)));
+}
+
+#[test]
+fn test_downtime_response() {
+    let res: DowntimeResponse = serde_json::from_str(r#"{
+        "data": {
+            "id": "00000000-0000-1234-0000-000000000000",
+            "type": "downtime",
+            "attributes": {
+                "monitor_identifier": {"monitor_id": 123},
+                "schedule": {"start": "2024-01-01T00:00:00+00:00", "end": null},
+                "scope": "env:prod",
+                "status": "active"
+            },
+            "relationships": {"monitor": {"data": {"id": 123, "type": "monitors"}}}
+        }
+    }"#).unwrap();
+    let attributes = res.data.attributes;
+    assert_eq!(DowntimeMonitorIdentifier::Id { monitor_id: 123 }, attributes.monitor_identifier);
+    assert_eq!(Some(DowntimeStatus::Active), attributes.status);
+    match attributes.schedule {
+        DowntimeSchedule::OneTime(schedule) => assert_eq!(Some("2024-01-01T00:00:00+00:00".into()), schedule.start),
+        schedule => panic!("Unexpected schedule {:?}", schedule),
+    }
+
+    let req = CreateDowntimeRequest::from(MuteMonitorRequest::default().monitor_id(123).scope("host:web-1").end(1700000000));
+    let attributes = req.data.attributes;
+    assert_eq!(Some(DowntimeMonitorIdentifier::Id { monitor_id: 123 }), attributes.monitor_identifier);
+    assert_eq!(Some("host:web-1".into()), attributes.scope);
+    match attributes.schedule {
+        Some(DowntimeSchedule::OneTime(schedule)) => assert_eq!(Some("2023-11-14T22:13:20+00:00".into()), schedule.end),
+        schedule => panic!("Unexpected schedule {:?}", schedule),
+    }
+}
@@ -50,3 +50,58 @@ fn paginated_search() {
         panic!("Only a single page of results was found")
     }
 }
+
+#[test]
+fn export_ndjson() {
+    let client = Client::default();
+    let path = std::env::temp_dir().join("datadog-api-rs-export.ndjson");
+    let filter = Filter::default()
+        .from("now-1h")
+        .to("now");
+    let req = ExportRequest::default()
+        .search(SearchRequest::default().filter(filter))
+        .path(path.to_str().unwrap())
+        .chunks(2);
+    let res = block_on(req.send(&client)).expect("API call failed");
+    assert_eq!(2, res.files.len());
+}
+
+#[test]
+fn column_flattens_nested_attributes() {
+    let log: Data = serde_json::from_str(r#"{
+        "id": "AAAA",
+        "type": "log",
+        "attributes": {
+            "service": "api",
+            "tags": ["env:prod"],
+            "attributes": { "http": { "status_code": 500 } }
+        }
+    }"#).unwrap();
+    assert_eq!("api", log.column("service"));
+    assert_eq!("[\"env:prod\"]", log.column("tags"));
+    assert_eq!("500", log.column("attributes.http.status_code"));
+    assert_eq!("", log.column("attributes.http.method"));
+}
+
+#[test]
+fn split_time_range_into_chunks() {
+    let now = chrono::Utc::now();
+    let from = parse_time("now-1d", now).unwrap();
+    let to = parse_time("now", now).unwrap();
+    let chunks = split_time_range(from, to, 4);
+    assert_eq!(4, chunks.len());
+    assert_eq!(from, chunks[0].0);
+    assert_eq!(chunks[0].1, chunks[1].0);
+    assert_eq!(to, chunks[3].1);
+    assert!(parse_time("now-1y", now).is_err());
+}
+
+#[test]
+fn parse_time_rejects_invalid_date_math() {
+    let now = chrono::Utc::now();
+    assert!(parse_time("now-5µ", now).is_err());
+    assert!(parse_time("now-", now).is_err());
+    assert!(parse_time("now-99999999999999w", now).is_err());
+    assert!(parse_time("now-9999999999d", now).is_err());
+    assert_eq!(now - chrono::Duration::minutes(5), parse_time("now-5m", now).unwrap());
+}
@@ -1,9 +1,6 @@
-use std::pin::pin;
-
 use datadog_api::Client;
 use datadog_api::monitors::*;
 use tokio_test::block_on;
-use std::future::{poll_fn, Future};
 use futures_util::StreamExt;
 
 /// Minimal search for monitors
@@ -13,14 +10,14 @@ fn test_monitors_search() {
     let req = MonitorsSearchRequest::default()
         // .query("tags(\"team:security\")");
         .query("security");
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
 }
 
 /// Iterable search for monitors
 #[test]
 fn test_monitors_search_iter() {
-    let res = block_on(async {
+    block_on(async {
         let client = Client::default();
         let req = MonitorsSearchRequest::default()
             .query("security");
@@ -37,7 +34,7 @@ fn test_create_monitor() {
     let req = CreateMonitorRequest::default()
         .query("min(last_4h):sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{is_compliant:true} / sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{*} * 100 < 97")
         ._type(MonitorType::MetricAlert);
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
 }
 
@@ -49,7 +46,7 @@ fn test_edit_monitor() {
         .monitor_id(55679649)
         .query("min(last_4h):sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{is_compliant:true} / sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{*} * 100 < 97")
         ._type(MonitorType::MetricAlert);
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
 }
 
@@ -59,6 +56,544 @@ fn test_delete_monitor() {
     let client = Client::default();
     let req = DeleteMonitorRequest::default()
         .monitor_id(55680057);
-    let res = block_on(req.send(&client)).expect("API call failed");
+    let _res = block_on(req.send(&client)).expect("API call failed");
     // assert_ne!(0, res.status);
-}
\ No newline at end of file
+}
+
+/// Gets a monitor with its group states
+#[test]
+fn test_get_monitor() {
+    let client = Client::default();
+    let req = GetMonitorRequest::default()
+        .monitor_id(55679649)
+        .group_states("all")
+        .with_downtimes(true);
+    let res = block_on(req.send(&client)).expect("API call failed");
+    assert_eq!(55679649, res.id);
+}
+
+/// Iterable list of monitors
+#[test]
+fn test_list_monitors_iter() {
+    block_on(async {
+        let client = Client::default();
+        let req = ListMonitorsRequest::default()
+            .monitor_tags("team:security")
+            .page_size(10);
+        let v: Vec<GetMonitorResponse> = req.iter(&client).collect().await;
+        assert_ne!(0, v.len());
+    });
+}
+
+/// Iterable search for monitor groups
+#[test]
+fn test_monitor_groups_search_iter() {
+    block_on(async {
+        let client = Client::default();
+        let req = MonitorGroupsSearchRequest::default()
+            .query("status:alert")
+            .per_page(10);
+        let v: Vec<MonitorGroup> = req.iter(&client).collect().await;
+        for group in v {
+            assert_ne!(0, group.monitor_id);
+            assert!(!group.group.is_empty());
+            assert_eq!(Status::Alert, group.status);
+        }
+    });
+}
+
+/// Percent-encodes the monitor group search query
+#[test]
+fn test_monitor_groups_search_path() {
+    let req = MonitorGroupsSearchRequest::default()
+        .query("tag:\"a&b\" #prod +1")
+        .page(2)
+        .per_page(10)
+        .sort("status,asc");
+    assert_eq!(
+        "/api/v1/monitor/groups/search?query=tag%3A%22a%26b%22+%23prod+%2B1&page=2&per_page=10&sort=status%2Casc",
+        req.path_and_query()
+    );
+    assert_eq!("/api/v1/monitor/groups/search?query=", MonitorGroupsSearchRequest::default().path_and_query());
+}
+
+/// Validates a monitor definition before creating it
+#[test]
+fn test_validate_monitor() {
+    let client = Client::default();
+    let monitor = CreateMonitorRequest::default()
+        .query("min(last_4h):sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{*} < 97")
+        ._type(MonitorType::MetricAlert);
+    let req = ValidateMonitorRequest::from(monitor);
+    block_on(req.send(&client)).expect("API call failed");
+}
+
+/// Checks whether a monitor can be deleted
+#[test]
+fn test_can_delete_monitors() {
+    let client = Client::default();
+    let req = CanDeleteMonitorsRequest::default()
+        .monitor_ids(vec![55679649]);
+    let _res = block_on(req.send(&client)).expect("API call failed");
+    // assert!(res.can_delete(55679649));
+}
+
+/// Maps Datadog error bodies into per-field messages
+#[test]
+fn test_monitor_validation_errors() {
+    let errors = MonitorValidationErrors::from_body(r#"{"errors": ["The value provided for parameter 'query' is invalid", "options.thresholds: critical is required", "Something went wrong"]}"#);
+    assert_eq!(3, errors.errors.len());
+    assert_eq!(Some("query".to_string()), errors.errors[0].field);
+    assert_eq!("critical is required", errors.field("options.thresholds")[0].message);
+    assert_eq!(None, errors.errors[2].field);
+
+    let errors = MonitorValidationErrors::from_body(r#"{"errors": {"123": ["monitor [123] is referenced in composite monitors: [456]"]}}"#);
+    assert_eq!(1, errors.field("123").len());
+}
+
+/// Round-trips a fetched monitor's options without inventing defaults
+#[test]
+fn test_monitor_options_round_trip() {
+    let monitor: GetMonitorResponse = serde_json::from_str(r#"{
+        "id": 1,
+        "name": "Bucket compliance",
+        "query": "min(last_4h):sum:foo.gauge{*} < 97",
+        "type": "metric alert",
+        "message": "",
+        "tags": [],
+        "options": {
+            "notify_no_data": true,
+            "thresholds": { "critical": 97, "warning": 98.5 },
+            "on_missing_data": "show_and_notify_no_data",
+            "notification_preset_name": "hide_query",
+            "scheduling_options": { "evaluation_window": { "day_starts": "04:00", "month_starts": 1 } }
+        }
+    }"#).unwrap();
+    let req = EditMonitorRequest::from(monitor);
+    let options = serde_json::to_value(&req.options).unwrap();
+    assert_eq!(serde_json::json!({
+        "notify_no_data": true,
+        "thresholds": { "critical": 97, "warning": 98.5 },
+        "on_missing_data": "show_and_notify_no_data",
+        "notification_preset_name": "hide_query",
+        "scheduling_options": { "evaluation_window": { "day_starts": "04:00", "month_starts": 1 } }
+    }), options);
+}
+
+/// Parses a metric monitor query and renders it back unchanged
+#[test]
+fn test_monitor_query_round_trip() {
+    let queries = vec![
+        "min(last_4h):sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{is_compliant:true} / sum:meetkunde.kci.aws.s3.bucket_effective_access_is_restricted_appropriately.gauge{*} * 100 < 97",
+        "avg(last_5m):avg:system.cpu.user{env:prod,role:db} by {host} > 90",
+        "pct_change(avg(last_5m),last_1h):sum:requests.count{*}.as_count() > 50",
+        "avg(last_4h):anomalies(avg:system.load.1{*} by {host}, 'basic', 2, direction='above') >= 1",
+        "max(last_1w):forecast(max:system.disk.in_use{*} by {host,device}, 'linear', 1) >= 0.95",
+        "avg(last_1h):outliers(avg:cpu{*} by {host}.rollup(avg, 60), 'dbscan', 2) > 0",
+        "sum(last_10m):(sum:a{*} - sum:b{*}) / sum:c{*} > 0.5",
+    ];
+    for query in queries {
+        let parsed = MonitorQuery::parse(query).expect("Failed to parse");
+        assert_eq!(query, parsed.to_string());
+    }
+}
+
+/// Builds a metric monitor query and checks its threshold
+#[test]
+fn test_monitor_query_builder() {
+    let compliant = MetricQuery::default()
+        .space_aggregation(SpaceAggregator::Sum)
+        .metric("foo.gauge")
+        .scope("is_compliant:true");
+    let all = MetricQuery::default()
+        .space_aggregation(SpaceAggregator::Sum)
+        .metric("foo.gauge");
+    let query = MonitorQuery::default()
+        .time_aggregation(TimeAggregator::Min, "last_4h")
+        .expression(QueryExpression::from(compliant) / all * 100.0)
+        .comparator(Comparator::Below)
+        .threshold(97);
+    assert_eq!("min(last_4h):sum:foo.gauge{is_compliant:true} / sum:foo.gauge{*} * 100 < 97", query.to_string());
+
+    assert!(query.check_thresholds(&Thresholds::default().critical(97).warning(98)).is_ok());
+    assert!(query.check_thresholds(&Thresholds::default().critical(95)).is_err());
+    assert!(query.check_thresholds(&Thresholds::default().critical(97).warning(90)).is_err());
+
+    let req = CreateMonitorRequest::default().monitor_query(&query);
+    assert_eq!(Some(ThresholdValue::Integer(97)), req.options.unwrap().thresholds.unwrap().critical);
+
+    let err = MonitorQuery::parse("avg(last_5m):avg:cpu{*} >").unwrap_err();
+    assert_eq!("Expected a number", err.message);
+}
+
+#[test]
+fn test_event_monitor_query_round_trip() {
+    let queries = vec![
+        r#"logs("service:api status:error").index("*").rollup("count").by("host").last("5m") > 100"#,
+        r#"trace-analytics("env:prod service:web").rollup("avg", "@duration").by("resource_name,env").last("15m") >= 2.5"#,
+        r#"rum("@type:error @application.id:\"abc\"").rollup("cardinality", "@usr.id").last("1h") > 10"#,
+        r#"events("source:github").rollup("count").last("30m") < 1"#,
+        r#"audits("@evt.name:\"Request\" @asset.type:monitor").rollup("count").last("5m")"#,
+    ];
+    for query in queries {
+        let parsed = EventMonitorQuery::parse(query).unwrap();
+        assert_eq!(query, parsed.to_string());
+    }
+
+    let err = EventMonitorQuery::parse(r#"metrics("*").last("5m") > 1"#).unwrap_err();
+    assert_eq!("Unknown query source `metrics`", err.message);
+}
+
+#[test]
+fn test_event_monitor_query_builder() {
+    let query = EventMonitorQuery::logs("service:api status:error")
+        .index("main")
+        .index("audit")
+        .by("host")
+        .last("10m")
+        .threshold(100);
+    assert_eq!(
+        r#"logs("service:api status:error").index("main,audit").rollup("count").by("host").last("10m") > 100"#,
+        query.to_string()
+    );
+    let req = CreateMonitorRequest::default().event_query(&query);
+    assert_eq!(MonitorType::LogAlert, req._type);
+    assert_eq!(Some(ThresholdValue::Integer(100)), req.options.unwrap().thresholds.unwrap().critical);
+
+    let query = EventMonitorQuery::trace_analytics("service:web").rollup_measure("pc99", "@duration");
+    assert_eq!(MonitorType::TraceAnalyticsAlert, query.monitor_type());
+    assert_eq!(MonitorType::RumAlert, EventMonitorQuery::rum("*").monitor_type());
+    assert_eq!(MonitorType::EventV2Alert, EventMonitorQuery::events("*").monitor_type());
+    assert_eq!(MonitorType::AuditAlert, EventMonitorQuery::audits("*").monitor_type());
+
+    let query = EventMonitorQuery::rum("@type:error")
+        .comparator(Comparator::Below)
+        .threshold(5);
+    assert!(query.check_thresholds(&Thresholds::default().critical(5).warning(10)).is_ok());
+    assert!(query.check_thresholds(&Thresholds::default().critical(5).warning(1)).is_err());
+}
+
+/// Checks the monitors referenced by a composite monitor exist
+#[test]
+fn test_composite_monitor_check() {
+    let client = Client::default();
+    let expression = CompositeExpression::from(55679649) & !CompositeExpression::from(55679649);
+    let req = block_on(expression.request(&client)).expect("API call failed");
+    assert_eq!(MonitorType::Composite, req._type);
+}
+
+#[test]
+fn test_composite_expression() {
+    let expression = CompositeExpression::from(12345) & (CompositeExpression::from(67890) | !CompositeExpression::from(11111));
+    assert_eq!("12345 && (67890 || !11111)", expression.to_string());
+    assert_eq!(expression, CompositeExpression::parse("12345 && (67890 || !11111)").unwrap());
+    assert_eq!(vec![12345, 67890, 11111], expression.monitor_ids());
+    assert_eq!("!(1 && 2) || 3", CompositeExpression::parse(" !(1&&2)||3 ").unwrap().to_string());
+    assert!(CompositeExpression::parse("1 && abc").is_err());
+
+    let found = Monitor {
+        id: 67890,
+        ..Default::default()
+    };
+    assert_eq!("12345 && 67890", (CompositeExpression::from(12345) & &found).to_string());
+
+    let req = CreateMonitorRequest::default().name("Both down").composite(&expression);
+    assert_eq!(MonitorType::Composite, req._type);
+    assert_eq!("12345 && (67890 || !11111)", req.query);
+}
+
+#[test]
+fn test_composite_expression_check_against() {
+    let monitor = |id: u64, _type: MonitorType, query: &str| {
+        GetMonitorResponse {
+            id,
+            _type,
+            query: query.into(),
+            ..Default::default()
+        }
+    };
+    let monitors = vec![
+        monitor(1, MonitorType::MetricAlert, "avg(last_5m):avg:cpu{*} > 90"),
+        monitor(2, MonitorType::Composite, "1 || 3"),
+        monitor(3, MonitorType::Composite, "1 && !4"),
+    ];
+
+    let expression = CompositeExpression::from(1) & 2;
+    assert!(expression.check_against(None, &monitors).is_ok());
+
+    let err = (CompositeExpression::from(1) & 99).check_against(None, &monitors).unwrap_err();
+    assert_eq!("query: Monitor 99 does not exist", err.to_string());
+
+    // Monitor 4 is edited to reference 2, which references 3, which references 4.
+    let err = expression.check_against(Some(4), &monitors).unwrap_err();
+    assert_eq!("query: Composite monitors reference each other in a cycle: 4 -> 2 -> 3 -> 4", err.to_string());
+}
+
+#[test]
+fn test_monitor_message_builder() {
+    let message = MonitorMessage::default()
+        .line("CPU usage is {{value}}")
+        .text("on ")
+        .variable("host.name")
+        .block(MessageCondition::IsAlert, MonitorMessage::default()
+            .text(" ")
+            .handle(NotificationHandle::PagerDuty("ops".into())))
+        .block(MessageCondition::IsRecovery, MonitorMessage::default()
+            .text(" ")
+            .handle(NotificationHandle::Slack("ops-alerts".into())))
+        .unless(MessageCondition::IsMatch("env.name".into(), "prod".into()), MonitorMessage::default()
+            .text(" ")
+            .handle(NotificationHandle::Email("dev@example.com".into())));
+    assert_eq!(
+        "CPU usage is {{value}}\non {{host.name}}{{#is_alert}} @pagerduty-ops{{/is_alert}}{{#is_recovery}} @slack-ops-alerts{{/is_recovery}}{{^is_match \"env.name\" \"prod\"}} @dev@example.com{{/is_match}}",
+        message.to_string()
+    );
+    assert!(message.validate_for(&MonitorType::MetricAlert).is_ok());
+
+    let req = CreateMonitorRequest::default().monitor_message(&message);
+    assert_eq!(Some(message.to_string()), req.message);
+}
+
+#[test]
+fn test_monitor_message_validate() {
+    let err = MonitorMessage::validate("{{#is_alert}}High {{#is_warning}}{{/is_alert}} @slack-", &MonitorType::MetricAlert).unwrap_err();
+    let messages: Vec<String> = err.field("message").iter().map(|error| error.message.clone()).collect();
+    assert_eq!(
+        vec![
+            "`{{/is_alert}}` closes `{{#is_warning}}`",
+            "Unclosed block `{{#is_alert}}`",
+            "Empty notification handle `@slack-`",
+        ],
+        messages
+    );
+
+    assert!(MonitorMessage::validate("{{log.message}} {{{value}}}", &MonitorType::LogAlert).is_ok());
+    let err = MonitorMessage::validate("{{log.message}} {{#is_alrt}}{{/is_alrt}}", &MonitorType::MetricAlert).unwrap_err();
+    assert_eq!(
+        "message: Unknown template variable `{{log.message}}` for metric alert monitors\nmessage: Unknown block `{{#is_alrt}}`",
+        err.to_string()
+    );
+}
+
+/// Plans syncing monitors defined in YAML
+#[test]
+fn test_monitor_reconciler_plan() {
+    let client = Client::default();
+    let file = MonitorsFile::from_yaml("monitors:\n  - name: datadog-api reconcile test\n    type: metric alert\n    query: avg(last_5m):avg:system.cpu.user{*} > 90\n").unwrap();
+    let reconciler = MonitorReconciler::default()
+        .monitors(file.monitors)
+        .managed_by("datadog-api-tests");
+    let plan = block_on(reconciler.plan(&client)).expect("API call failed");
+    assert_eq!(1, plan.changes.len());
+    assert_eq!("datadog-api reconcile test", plan.changes[0].name);
+    assert_ne!(ReconcileAction::Delete, plan.changes[0].action);
+    assert!(plan.with_action(ReconcileAction::Delete).is_empty());
+    let desired = plan.changes[0].desired.as_ref().unwrap();
+    assert!(desired.tags.iter().flatten().any(|tag| tag == "managed-by:datadog-api-tests"));
+}
+
+#[test]
+fn test_monitor_reconciler_plan_against() {
+    let yaml = r#"
+monitors:
+  - name: High CPU
+    type: metric alert
+    query: avg(last_5m):avg:system.cpu.user{*} > 90
+    options:
+      thresholds:
+        critical: 90
+  - name: Disk full
+    type: metric alert
+    query: avg(last_5m):avg:system.disk.in_use{*} > 0.9
+    tags: ["team:infra"]
+  - name: New monitor
+    type: log alert
+    query: logs("status:error").index("*").rollup("count").last("5m") > 10
+"#;
+    let file = MonitorsFile::from_yaml(yaml).unwrap();
+    let existing = vec![
+        GetMonitorResponse {
+            id: 1,
+            name: "High CPU".into(),
+            _type: MonitorType::MetricAlert,
+            query: "avg(last_5m):avg:system.cpu.user{*} > 80".into(),
+            options: Some(MonitorOptions::default().thresholds(Thresholds::default().critical(80.0))),
+            tags: vec!["managed-by:git".into()],
+            ..Default::default()
+        },
+        GetMonitorResponse {
+            id: 2,
+            name: "Disk full".into(),
+            _type: MonitorType::MetricAlert,
+            query: "avg(last_5m):avg:system.disk.in_use{*} > 0.9".into(),
+            tags: vec!["managed-by:git".into(), "team:infra".into()],
+            ..Default::default()
+        },
+        GetMonitorResponse {
+            id: 3,
+            name: "Removed".into(),
+            tags: vec!["managed-by:git".into()],
+            ..Default::default()
+        },
+    ];
+
+    let reconciler = MonitorReconciler::default()
+        .monitors(file.monitors)
+        .managed_by("git");
+    let plan = reconciler.plan_against(&existing).unwrap();
+    let actions: Vec<ReconcileAction> = plan.changes.iter().map(|change| change.action.clone()).collect();
+    assert_eq!(vec![ReconcileAction::Update, ReconcileAction::NoOp, ReconcileAction::Create], actions);
+    let fields: Vec<&str> = plan.changes[0].diffs.iter().map(|diff| diff.field.as_str()).collect();
+    assert_eq!(vec!["options.thresholds.critical", "query"], fields);
+    assert!(plan.to_string().ends_with("Plan: 1 to create, 1 to update, 0 to delete, 1 unchanged."));
+
+    let plan = reconciler.clone().prune(true).plan_against(&existing).unwrap();
+    let deleted = plan.with_action(ReconcileAction::Delete);
+    assert_eq!(1, deleted.len());
+    assert_eq!("Removed", deleted[0].name);
+
+    let duplicated = MonitorReconciler::default().monitors(vec![
+        CreateMonitorRequest::default().name("Same"),
+        CreateMonitorRequest::default().name("Same"),
+    ]);
+    assert!(duplicated.plan_against(&[]).is_err());
+}
+
+/// Mutes a scope of a monitor for an hour, then unmutes it
+#[test]
+fn test_mute_monitor() {
+    let client = Client::default();
+    let end = chrono::Utc::now().timestamp() + 3600;
+    let req = MuteMonitorRequest::default()
+        .monitor_id(55679649)
+        .scope("host:web-1")
+        .end(end);
+    let res = block_on(req.send(&client)).expect("API call failed");
+    assert!(res.options.unwrap().is_silenced("host:web-1", end - 1));
+
+    let req = UnmuteMonitorRequest::default()
+        .monitor_id(55679649)
+        .scope("host:web-1");
+    let _res = block_on(req.send(&client)).expect("API call failed");
+}
+
+#[test]
+fn test_monitor_options_silenced() {
+    let options: MonitorOptions = serde_json::from_str(r#"{"silenced": {"*": null, "host:web-1": 1700000000}}"#).unwrap();
+    let silenced = options.silenced.clone().unwrap();
+    assert_eq!(Some(&None), silenced.get("*"));
+    assert_eq!(Some(&Some(1700000000)), silenced.get("host:web-1"));
+    assert!(options.is_silenced("host:web-2", 1800000000));
+
+    let options = MonitorOptions::default().silence("host:web-1", Some(1700000000));
+    assert!(options.is_silenced("host:web-1", 1600000000));
+    assert!(!options.is_silenced("host:web-1", 1700000000));
+    assert!(!options.is_silenced("host:web-2", 1600000000));
+    assert_eq!(r#"{"silenced":{"host:web-1":1700000000}}"#, serde_json::to_string(&options).unwrap());
+}
+
+#[test]
+fn test_monitor_snapshot_transitions() {
+    let group = |monitor_id: u64, group: &str, status: Status| MonitorGroup {
+        monitor_id,
+        monitor_name: format!("monitor {}", monitor_id),
+        group: group.into(),
+        status,
+        ..Default::default()
+    };
+    let before = MonitorSnapshot::from_groups(vec![
+        group(1, "host:a", Status::Ok),
+        group(1, "host:b", Status::Alert),
+        group(2, "*", Status::Ok),
+    ]);
+    let after = MonitorSnapshot::from_groups(vec![
+        group(1, "host:a", Status::Warn),
+        group(1, "host:b", Status::Alert),
+        group(1, "host:c", Status::Alert),
+    ]);
+    let now = chrono::Utc::now();
+    let transitions = before.transitions(&after, now);
+    let changes: Vec<(u64, &str, Status, Status)> = transitions
+        .iter()
+        .map(|t| (t.monitor_id, t.group.as_str(), t.old_status.clone(), t.new_status.clone()))
+        .collect();
+    assert_eq!(
+        vec![
+            (1, "host:a", Status::Ok, Status::Warn),
+            (1, "host:c", Status::Unknown, Status::Alert),
+        ],
+        changes
+    );
+    assert_eq!(now, transitions[0].timestamp);
+
+    let interval = std::time::Duration::from_secs(10);
+    for _ in 0..100 {
+        let wait = jittered(interval);
+        assert!(wait >= std::time::Duration::from_secs(8) && wait < std::time::Duration::from_secs(12));
+    }
+}
+
+#[test]
+fn test_watch_survives_api_errors() {
+    block_on(async {
+        let client = Client::new("http://127.0.0.1:9", "api-key", "app-key");
+        let stream = watch(&client, "status:alert", std::time::Duration::from_millis(10));
+        let res = tokio::time::timeout(std::time::Duration::from_millis(300), stream.take(1).collect::<Vec<_>>()).await;
+        assert!(res.is_err());
+    });
+}
+
+#[test]
+fn test_monitor_lint() {
+    let linter = MonitorLinter::default();
+    let bad = CreateMonitorRequest::default()
+        .name("heartbeat")
+        ._type(MonitorType::ServiceCheck)
+        .query("\"datadog.agent.up\".over(\"*\").by(\"host\").last(2).count_by_status()")
+        .message("Agent {{host.name}} is down {{#is_alert}}")
+        .priority(1)
+        .tags(vec!["service:agent".into()]);
+    let rules: Vec<String> = linter.lint(&bad).into_iter().map(|finding| finding.rule).collect();
+    assert_eq!(
+        vec!["required-tag-team", "heartbeat-notify-no-data", "p1-renotify", "message-routing", "message-template"],
+        rules
+    );
+
+    let good = bad
+        .message("Agent {{host.name}} is down @slack-sre")
+        .tags(vec!["team:sre".into()])
+        .options(MonitorOptions::default().notify_no_data(true).renotify_interval(30));
+    assert_eq!(Vec::<LintFinding>::new(), linter.lint(&good));
+
+    let linter = MonitorLinter::empty().custom("name-prefix", Severity::Warning, |monitor| {
+        match monitor.name.as_deref().unwrap_or("").starts_with('[') {
+            true => None,
+            false => Some("The name should start with `[service]`".into()),
+        }
+    });
+    let findings = linter.lint_monitor(&GetMonitorResponse {
+        id: 42,
+        name: "heartbeat".into(),
+        ..Default::default()
+    });
+    assert_eq!(1, findings.len());
+    assert_eq!("warning [name-prefix] `heartbeat` (42): The name should start with `[service]`", findings[0].to_string());
+}
+
+#[test]
+fn test_monitor_lint_file() {
+    let path = std::env::temp_dir().join("datadog-api-lint-test.yaml");
+    std::fs::write(&path, "monitors:\n  - name: cpu\n    type: metric alert\n    query: avg(last_5m):avg:system.cpu.user{*} > 90\n    message: CPU is high @slack-sre\n    priority: 3\n").unwrap();
+    let findings = MonitorLinter::default().lint_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(1, findings.len());
+    assert_eq!("required-tag-team", findings[0].rule);
+    assert_eq!(Severity::Error, findings[0].severity);
+}
+
+#[test]
+fn test_monitor_lint_search() {
+    let client = Client::default();
+    let findings = block_on(MonitorLinter::default().lint_search(&client, "security")).expect("API call failed");
+    assert!(findings.iter().all(|finding| finding.monitor_id.is_some()));
+}
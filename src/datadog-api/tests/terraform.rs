@@ -0,0 +1,68 @@
+use datadog_api::dashboards::*;
+use datadog_api::monitors::*;
+use datadog_api::terraform::*;
+
+#[test]
+fn test_export_monitor() {
+    let monitor = GetMonitorResponse {
+        id: 12345,
+        name: "High CPU on ${env}".into(),
+        _type: MonitorType::MetricAlert,
+        query: "avg(last_5m):avg:system.cpu.user{*} > 90".into(),
+        message: "{{#is_alert}}CPU is \"{{value}}\"{{/is_alert}}\n@slack-ops".into(),
+        priority: Some(2),
+        tags: vec!["team:infra".into(), "env:prod".into()],
+        options: Some(MonitorOptions {
+            new_host_delay: Some(0),
+            ..MonitorOptions::default()
+                .include_tags(true)
+                .notify_no_data(false)
+                .no_data_timeframe(10)
+                .renotify_interval(60)
+                .thresholds(Thresholds::default().critical(90.0).warning(80))
+        }),
+        ..Default::default()
+    };
+    let hcl = TerraformExport::default().monitor(monitor).imports(true).render();
+    assert_eq!(
+        r#"import {
+  to = datadog_monitor.high_cpu_on_env
+  id = "12345"
+}
+
+resource "datadog_monitor" "high_cpu_on_env" {
+  name              = "High CPU on $${env}"
+  type              = "metric alert"
+  query             = "avg(last_5m):avg:system.cpu.user{*} > 90"
+  message           = "{{#is_alert}}CPU is \"{{value}}\"{{/is_alert}}\n@slack-ops"
+  priority          = "2"
+  tags              = ["env:prod", "team:infra"]
+  new_host_delay    = 0
+  renotify_interval = 60
+  monitor_thresholds {
+    critical = 90
+    warning  = 80
+  }
+}
+"#,
+        hcl
+    );
+}
+
+#[test]
+fn test_export_dashboard() {
+    let dashboard = CreateDashboardResponse {
+        id: "abc-def-ghi".into(),
+        title: "Service overview".into(),
+        widgets: vec![Widget { id: Some(1), ..Default::default() }],
+        ..Default::default()
+    };
+    let hcl = TerraformExport::default()
+        .dashboard(dashboard.clone())
+        .dashboard(dashboard)
+        .render();
+    assert!(hcl.contains("resource \"datadog_dashboard_json\" \"service_overview\" {\n  dashboard = <<-EOT\n{"));
+    assert!(hcl.contains("resource \"datadog_dashboard_json\" \"service_overview_2\""));
+    assert!(!hcl.contains("\"id\""));
+    assert!(!hcl.contains("is_read_only"));
+}